@@ -43,3 +43,43 @@ mod scalar;
 
 #[cfg(feature = "simd")]
 mod simd;
+
+#[cfg(test)]
+mod test {
+    use arrow::array::PrimitiveArray;
+    use rand::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn test_min_max_with_validity() {
+        // Cover lengths around the SIMD lane widths and masks straddling the word boundary.
+        let mut rng = StdRng::seed_from_u64(0xdeadbeef);
+        for len in [1, 7, 63, 64, 65, 130] {
+            let values = (0..len)
+                .map(|_| rng.gen_bool(0.7).then(|| rng.gen_range(-100i32..100)))
+                .collect::<Vec<_>>();
+            let arr = PrimitiveArray::<i32>::from(values.as_slice());
+            let valid = values.iter().flatten().copied();
+
+            assert_eq!(arr.min_ignore_nan_kernel(), valid.clone().min());
+            assert_eq!(arr.max_ignore_nan_kernel(), valid.clone().max());
+            assert_eq!(
+                arr.min_max_ignore_nan_kernel(),
+                valid.clone().min().zip(valid.max())
+            );
+        }
+    }
+
+    #[test]
+    fn test_min_max_nan() {
+        let arr = PrimitiveArray::<f64>::from([Some(1.0), Some(f64::NAN), None, Some(-2.0)]);
+        assert_eq!(arr.min_ignore_nan_kernel(), Some(-2.0));
+        assert_eq!(arr.max_ignore_nan_kernel(), Some(1.0));
+        assert!(arr.max_propagate_nan_kernel().unwrap().is_nan());
+
+        let arr = PrimitiveArray::<f64>::from([None, None]);
+        assert_eq!(arr.min_ignore_nan_kernel(), None);
+        assert_eq!(arr.min_max_propagate_nan_kernel(), None);
+    }
+}
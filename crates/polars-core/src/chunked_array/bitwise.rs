@@ -122,14 +122,14 @@ impl BitXor for &BooleanChunked {
                         rhs.rename(self.name().clone());
                         rhs
                     },
-                    None => &self.new_from_index(0, rhs.len()) | rhs,
+                    None => &self.new_from_index(0, rhs.len()) ^ rhs,
                 };
             },
             (_, 1) => {
                 return match rhs.get(0) {
                     Some(true) => self.not(),
                     Some(false) => self.clone(),
-                    None => self | &rhs.new_from_index(0, self.len()),
+                    None => self ^ &rhs.new_from_index(0, self.len()),
                 };
             },
             _ => {},
@@ -202,4 +202,20 @@ mod test {
         assert_eq!((&a).bitor(&b).null_count(), 1);
         assert_eq!((&a).bitxor(&b).null_count(), 1);
     }
+
+    #[test]
+    fn test_kleene_null_broadcast() {
+        let a = BooleanChunked::new(
+            PlSmallStr::from_static("a"),
+            [Some(true), Some(false), None],
+        );
+        let null = BooleanChunked::new(PlSmallStr::from_static("b"), [None]);
+
+        // `true | null` is true and `false & null` is false; everything else is unknown.
+        assert_eq!(Vec::from(&(&a | &null)), &[Some(true), None, None]);
+        assert_eq!(Vec::from(&(&a & &null)), &[None, Some(false), None]);
+        // Xor with an unknown value is always unknown.
+        assert_eq!(Vec::from(&(&a ^ &null)), &[None, None, None]);
+        assert_eq!(Vec::from(&(&null ^ &a)), &[None, None, None]);
+    }
 }
@@ -496,6 +496,26 @@ mod test {
         assert_eq!(appended.str_value(5).unwrap(), "y");
     }
 
+    #[test]
+    fn test_global_cache_shares_physical() -> PolarsResult<()> {
+        let _lock = SINGLE_LOCK.lock();
+        disable_string_cache();
+        let _sc = StringCacheHolder::hold();
+
+        let s1 = Series::new(PlSmallStr::from_static("1"), vec!["a", "b", "c"])
+            .cast(&DataType::Categorical(None, Default::default()))?;
+        let s2 = Series::new(PlSmallStr::from_static("2"), vec!["c", "a"])
+            .cast(&DataType::Categorical(None, Default::default()))?;
+        let ca1 = s1.categorical()?;
+        let ca2 = s2.categorical()?;
+
+        assert!(ca1.get_rev_map().is_global());
+        assert!(ca1.get_rev_map().same_src(ca2.get_rev_map()));
+        assert_eq!(ca1.physical().get(2), ca2.physical().get(0));
+        assert_eq!(ca1.physical().get(0), ca2.physical().get(1));
+        Ok(())
+    }
+
     #[test]
     fn test_fast_unique() {
         let _lock = SINGLE_LOCK.lock();
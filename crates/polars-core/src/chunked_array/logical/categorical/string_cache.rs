@@ -10,7 +10,7 @@ use polars_utils::pl_str::PlSmallStr;
 use crate::datatypes::{InitHashMaps2, PlIdHashMap};
 use crate::hashing::_HASHMAP_INIT_SIZE;
 
-/// We use atomic reference counting to determine how many threads use the
+/// We use a mutex-guarded reference count to determine how many threads use the
 /// string cache. If the refcount is zero, we may clear the string cache.
 static STRING_CACHE_REFCOUNT: Mutex<u32> = Mutex::new(0);
 static STRING_CACHE_ENABLED_GLOBALLY: AtomicBool = AtomicBool::new(false);
@@ -28,7 +28,7 @@ static STRING_CACHE_UUID_CTR: AtomicU32 = AtomicU32::new(0);
 /// let _sc = StringCacheHolder::hold();
 /// ```
 ///
-/// The string cache is enabled until `handle` is dropped.
+/// The string cache is enabled until `_sc` is dropped.
 ///
 /// # De-allocation
 ///
@@ -392,8 +392,34 @@ impl<T: PolarsObject> ObjectChunked<T> {
 
 #[cfg(test)]
 mod test {
-    #[cfg(feature = "dtype-categorical")]
     use crate::prelude::*;
+    use crate::series::IsSorted;
+
+    #[test]
+    fn test_slice_is_zero_copy() {
+        let mut ca = Int32Chunked::new(PlSmallStr::EMPTY, &[1, 2, 3]);
+        ca.append(&Int32Chunked::new(PlSmallStr::EMPTY, &[4, 5]))
+            .unwrap();
+        ca.set_sorted_flag(IsSorted::Ascending);
+
+        // The slice crosses the chunk boundary and points into the original buffers.
+        let sliced = ca.slice(2, 2);
+        assert_eq!(Vec::from(&sliced), &[Some(3), Some(4)]);
+        assert_eq!(sliced.chunks().len(), 2);
+        let orig = ca.downcast_iter().collect::<Vec<_>>();
+        let views = sliced.downcast_iter().collect::<Vec<_>>();
+        assert_eq!(views[0].values().as_ptr(), orig[0].values()[2..].as_ptr());
+        assert_eq!(views[1].values().as_ptr(), orig[1].values().as_ptr());
+        assert!(sliced.is_sorted_ascending_flag());
+
+        // Negative offsets count from the end.
+        let tail = ca.slice(-1, 5);
+        assert_eq!(Vec::from(&tail), &[Some(5)]);
+        assert_eq!(
+            tail.downcast_iter().next().unwrap().values().as_ptr(),
+            orig[1].values()[1..].as_ptr()
+        );
+    }
 
     #[test]
     #[cfg(feature = "dtype-categorical")]
@@ -135,7 +135,7 @@ macro_rules! sort_with_fast_path {
                 // if the nulls are already last we can clone
                 if $options.nulls_last && $ca.get($ca.len() - 1).is_none()  ||
                 // if the nulls are already first we can clone
-                $ca.get(0).is_none()
+                (!$options.nulls_last && $ca.get(0).is_none())
                 {
                     return $ca.clone();
                 }
@@ -872,6 +872,20 @@ mod test {
         assert_eq!(Vec::from(&out), &[Some(false), Some(false), Some(true)]);
     }
 
+    #[test]
+    fn test_sort_sorted_flag_moves_nulls() {
+        let a = Int32Chunked::new(PlSmallStr::from_static("a"), &[Some(2), None, Some(1)]);
+        let nulls_first = a.sort_with(SortOptions::default());
+        assert!(nulls_first.is_sorted_ascending_flag());
+        assert_eq!(Vec::from(&nulls_first), &[None, Some(1), Some(2)]);
+
+        // The sorted flag must not short-circuit when the nulls are on the wrong side.
+        let nulls_last = nulls_first.sort_with(SortOptions::default().with_nulls_last(true));
+        assert_eq!(Vec::from(&nulls_last), &[Some(1), Some(2), None]);
+        let nulls_first = nulls_last.sort_with(SortOptions::default());
+        assert_eq!(Vec::from(&nulls_first), &[None, Some(1), Some(2)]);
+    }
+
     #[test]
     #[cfg_attr(miri, ignore)]
     fn test_arg_sort_multiple() -> PolarsResult<()> {
@@ -942,6 +956,42 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_sort_maintain_order() -> PolarsResult<()> {
+        // Many ties, so that an unstable sort would likely reorder the row index.
+        let n = 10_000;
+        let df = df!(
+            "k1" => (0..n).map(|i| (i * 31) % 7).collect::<Vec<i32>>(),
+            "k2" => (0..n).map(|i| Some((i * 17) % 3)).collect::<Vec<_>>(),
+            "idx" => (0..n).collect::<Vec<i32>>()
+        )?;
+
+        for multithreaded in [false, true] {
+            for descending in [false, true] {
+                let options = SortMultipleOptions::default()
+                    .with_order_descending(descending)
+                    .with_multithreaded(multithreaded)
+                    .with_maintain_order(true);
+                for by in [vec!["k1"], vec!["k1", "k2"]] {
+                    let out = df.sort(by.clone(), options.clone())?;
+                    let keys = out.select(by)?;
+                    let idx = out.column("idx")?.i32()?;
+                    for i in 1..out.height() {
+                        if keys
+                            .get_columns()
+                            .iter()
+                            .all(|s| s.get(i - 1).ok() == s.get(i).ok())
+                        {
+                            assert!(idx.get(i - 1) < idx.get(i));
+                        }
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
     #[test]
     fn test_sort_string() {
         let ca = StringChunked::new(
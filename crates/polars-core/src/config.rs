@@ -28,6 +28,8 @@ pub(crate) const FMT_TABLE_INLINE_COLUMN_DATA_TYPE: &str =
 #[cfg(any(feature = "fmt", feature = "fmt_no_tty"))]
 pub(crate) const FMT_TABLE_ROUNDED_CORNERS: &str = "POLARS_FMT_TABLE_ROUNDED_CORNERS";
 pub(crate) const FMT_TABLE_CELL_LIST_LEN: &str = "POLARS_FMT_TABLE_CELL_LIST_LEN";
+#[cfg(any(feature = "fmt", feature = "fmt_no_tty"))]
+pub(crate) const FMT_TABLE_WIDTH: &str = "POLARS_TABLE_WIDTH";
 
 pub fn verbose() -> bool {
     std::env::var("POLARS_VERBOSE").as_deref().unwrap_or("") == "1"
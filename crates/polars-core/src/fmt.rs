@@ -668,14 +668,7 @@ impl Display for DataFrame {
             }
 
             let tbl_fallback_width = 100;
-            let tbl_width = std::env::var("POLARS_TABLE_WIDTH")
-                .map(|s| {
-                    Some(
-                        s.parse::<u16>()
-                            .expect("could not parse table width argument"),
-                    )
-                })
-                .unwrap_or(None);
+            let tbl_width = parse_env_var::<u16>(FMT_TABLE_WIDTH);
 
             // column width constraints
             let col_width_exact =
@@ -1189,4 +1189,71 @@ mod test {
         let _ = df.group_by(["g"])?.sum()?;
         Ok(())
     }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_group_by_threaded_matches_single_threaded() {
+        use super::hashing::{group_by, group_by_threaded_iter, group_by_threaded_slice};
+
+        let keys = (0..2000i64).map(|i| (i * 7) % 13).collect::<Vec<_>>();
+        let (left, right) = keys.split_at(1500);
+        let expected = group_by(keys.iter().copied(), true);
+
+        // The keys are partitioned by hash over several tables and merged afterwards.
+        for n_partitions in [1, 3, 8] {
+            let out = group_by_threaded_slice(vec![left, right], n_partitions, true);
+            assert_eq!(out.unwrap_idx().first(), expected.unwrap_idx().first());
+            assert_eq!(out.unwrap_idx().all(), expected.unwrap_idx().all());
+
+            let chunks = [
+                left.iter().copied().map(Some),
+                right.iter().copied().map(Some),
+            ];
+            let out = group_by_threaded_iter(&chunks, n_partitions, true);
+            assert_eq!(out.unwrap_idx().first(), expected.unwrap_idx().first());
+        }
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_group_by_groups_and_count() -> PolarsResult<()> {
+        let df = df![
+            "g" => ["a", "b", "a", "c", "a"],
+            "v" => [1, 2, 3, 4, 5]
+        ]?;
+
+        let gb = df.group_by_stable(["g"])?;
+        let out = gb.groups()?;
+        assert_eq!(out.get_column_names(), &["g", "groups"]);
+        let groups = out.column("groups")?.list()?;
+        assert_eq!(groups.inner_dtype(), &IDX_DTYPE);
+        let groups = groups
+            .into_iter()
+            .map(|s| Vec::from(s.unwrap().idx().unwrap()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            groups,
+            &[
+                vec![Some(0), Some(2), Some(4)],
+                vec![Some(1)],
+                vec![Some(3)]
+            ]
+        );
+
+        // Use of deprecated `count()` for testing purposes
+        #[allow(deprecated)]
+        let out = gb.select(["v"]).count()?;
+        assert_eq!(
+            Vec::from(out.column("v_count")?.idx()?),
+            &[Some(3), Some(1), Some(1)]
+        );
+
+        let out = df.clear().group_by(["g"])?.groups()?;
+        assert_eq!(out.height(), 0);
+        assert_eq!(
+            out.column("groups")?.dtype(),
+            &DataType::List(Box::new(IDX_DTYPE))
+        );
+        Ok(())
+    }
 }
@@ -506,6 +506,10 @@ impl GroupsProxy {
         }
     }
     pub fn as_list_chunked(&self) -> ListChunked {
+        if self.is_empty() {
+            // Without any group the inner dtype cannot be inferred from the values.
+            return ListChunked::full_null_with_dtype(PlSmallStr::EMPTY, 0, &IDX_DTYPE);
+        }
         match self {
             GroupsProxy::Idx(groups) => groups
                 .iter()
@@ -1660,6 +1660,18 @@ impl DataFrame {
         Ok(unsafe { DataFrame::new_no_checks(new_col) })
     }
 
+    /// Take every nth row of the [`DataFrame`], starting at `offset`.
+    ///
+    /// The same row indices are gathered from every column.
+    pub fn gather_every(&self, n: usize, offset: usize) -> PolarsResult<Self> {
+        polars_ensure!(n > 0, InvalidOperation: "gather_every(n): n should be positive");
+        let idx = ((offset as IdxSize)..self.height() as IdxSize)
+            .step_by(n)
+            .collect_ca(PlSmallStr::EMPTY);
+        // SAFETY: we stay in-bounds.
+        Ok(unsafe { self.take_unchecked(&idx) })
+    }
+
     /// # Safety
     /// The indices must be in-bounds.
     pub unsafe fn take_unchecked(&self, idx: &IdxCa) -> Self {
@@ -2392,7 +2404,7 @@ impl DataFrame {
     /// Get a [`DataFrame`] with all the columns in reversed order.
     #[must_use]
     pub fn reverse(&self) -> Self {
-        let col = self.columns.iter().map(|s| s.reverse()).collect::<Vec<_>>();
+        let col = self._apply_columns_par(&|s| s.reverse());
         unsafe { DataFrame::new_no_checks(col) }
     }
 
@@ -2969,7 +2981,8 @@ impl DataFrame {
     }
 
     /// Unnest the given `Struct` columns. This means that the fields of the `Struct` type will be
-    /// inserted as columns.
+    /// inserted as columns, in place of the `Struct` column. Rows where the `Struct` is null are
+    /// null in every field column.
     #[cfg(feature = "dtype-struct")]
     pub fn unnest<I: IntoVec<PlSmallStr>>(&self, cols: I) -> PolarsResult<DataFrame> {
         let cols = cols.into_vec();
@@ -3099,6 +3112,60 @@ mod test {
         DataFrame::new(vec![s0, s1]).unwrap()
     }
 
+    #[test]
+    fn test_take_and_gather_every() -> PolarsResult<()> {
+        let df = df!(
+            "a" => [0, 1, 2, 3, 4],
+            "b" => ["a", "b", "c", "d", "e"]
+        )?;
+
+        let out = df.take(&IdxCa::new("idx".into(), [4, 0, 0]))?;
+        assert!(out.equals(&df!("a" => [4, 0, 0], "b" => ["e", "a", "a"])?));
+        assert!(df.take(&IdxCa::new("idx".into(), [5])).is_err());
+
+        let out = df.gather_every(2, 1)?;
+        assert!(out.equals(&df!("a" => [1, 3], "b" => ["b", "d"])?));
+        assert_eq!(df.gather_every(1, 10)?.shape(), (0, 2));
+        assert!(df.gather_every(0, 0).is_err());
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "dtype-struct")]
+    fn test_unnest() -> PolarsResult<()> {
+        let a = Series::new("a".into(), [1, 2, 3]);
+        let b = Series::new("b".into(), ["x", "y", "z"]);
+        let s = StructChunked::from_series("s".into(), &[a, b])?.into_series();
+        // Mark the second struct row as null.
+        let s = s.zip_with(
+            &BooleanChunked::new("m".into(), [true, false, true]),
+            &Series::full_null("s".into(), 3, s.dtype()),
+        )?;
+
+        let df = DataFrame::new(vec![
+            Series::new("before".into(), [0, 0, 0]),
+            s,
+            Series::new("after".into(), [9, 9, 9]),
+        ])?;
+        let out = df.unnest(["s"])?;
+        assert_eq!(out.get_column_names(), &["before", "a", "b", "after"]);
+        assert_eq!(
+            Vec::from(out.column("a")?.i32()?),
+            &[Some(1), None, Some(3)]
+        );
+        assert_eq!(
+            Vec::from(out.column("b")?.str()?),
+            &[Some("x"), None, Some("z")]
+        );
+
+        assert!(matches!(
+            df.unnest(["missing"]),
+            Err(PolarsError::ColumnNotFound(_))
+        ));
+        assert!(df.unnest(["before"]).is_err());
+        Ok(())
+    }
+
     #[test]
     #[cfg_attr(miri, ignore)]
     fn test_recordbatch_iterator() {
@@ -33,10 +33,14 @@ use std::time::{SystemTime, UNIX_EPOCH};
 
 pub use hashing::IdBuildHasher;
 use once_cell::sync::Lazy;
+#[cfg(not(target_family = "wasm"))]
+use once_cell::sync::OnceCell;
 use rayon::{ThreadPool, ThreadPoolBuilder};
 
 #[cfg(feature = "dtype-categorical")]
 pub use crate::chunked_array::logical::categorical::string_cache::*;
+#[cfg(not(target_family = "wasm"))]
+use crate::error::{polars_ensure, polars_err, polars_warn, PolarsResult};
 
 pub static PROCESS_ID: Lazy<u128> = Lazy::new(|| {
     SystemTime::now()
@@ -45,25 +49,76 @@ pub static PROCESS_ID: Lazy<u128> = Lazy::new(|| {
         .as_nanos()
 });
 
+// Number of threads of the global thread pool. This is set by `set_num_threads`, or when the
+// pool is created, so that the number can't change once the pool is running.
+#[cfg(not(target_family = "wasm"))]
+static NUM_THREADS: OnceCell<usize> = OnceCell::new();
+
 // this is re-exported in utils for polars child crates
 #[cfg(not(target_family = "wasm"))] // only use this on non wasm targets
 pub static POOL: Lazy<ThreadPool> = Lazy::new(|| {
     let thread_name = std::env::var("POLARS_THREAD_NAME").unwrap_or_else(|_| "polars".to_string());
+    let default_num_threads = || {
+        std::thread::available_parallelism()
+            .unwrap_or(std::num::NonZeroUsize::new(1).unwrap())
+            .get()
+    };
+    let num_threads = *NUM_THREADS.get_or_init(|| match std::env::var("POLARS_MAX_THREADS") {
+        Ok(s) => match s.parse::<usize>() {
+            Ok(n) if n > 0 => n,
+            _ => {
+                let n = default_num_threads();
+                polars_warn!(
+                    "POLARS_MAX_THREADS must be a positive integer, got {:?}; using {} threads",
+                    s,
+                    n
+                );
+                n
+            },
+        },
+        Err(_) => default_num_threads(),
+    });
+    if config::verbose() {
+        eprintln!("starting thread pool with {num_threads} threads");
+    }
     ThreadPoolBuilder::new()
-        .num_threads(
-            std::env::var("POLARS_MAX_THREADS")
-                .map(|s| s.parse::<usize>().expect("integer"))
-                .unwrap_or_else(|_| {
-                    std::thread::available_parallelism()
-                        .unwrap_or(std::num::NonZeroUsize::new(1).unwrap())
-                        .get()
-                }),
-        )
+        .num_threads(num_threads)
         .thread_name(move |i| format!("{}-{}", thread_name, i))
         .build()
         .expect("could not spawn threads")
 });
 
+/// Set the number of threads of the global thread pool [`POOL`].
+///
+/// This takes precedence over `POLARS_MAX_THREADS`, but the pool is only created once, so it
+/// must be called before polars runs anything in parallel. It can only be called once.
+///
+/// # Example
+///
+/// ```
+/// // Doc-tests run in their own process, so the pool doesn't exist yet.
+/// polars_core::set_num_threads(3).unwrap();
+/// assert_eq!(polars_core::thread_pool_size(), 3);
+/// assert!(polars_core::set_num_threads(4).is_err());
+/// ```
+#[cfg(not(target_family = "wasm"))]
+pub fn set_num_threads(n: usize) -> PolarsResult<()> {
+    polars_ensure!(n > 0, InvalidOperation: "the number of threads must be positive");
+    NUM_THREADS.set(n).map_err(|_| {
+        polars_err!(
+            InvalidOperation: "cannot set the number of threads, it is already set to {}",
+            NUM_THREADS.get().unwrap()
+        )
+    })
+}
+
+/// The number of threads of the global thread pool [`POOL`].
+///
+/// This creates the pool if it wasn't yet.
+pub fn thread_pool_size() -> usize {
+    POOL.current_num_threads()
+}
+
 #[cfg(target_family = "wasm")] // instead use this on wasm targets
 pub static POOL: Lazy<polars_utils::wasm::Pool> = Lazy::new(|| polars_utils::wasm::Pool);
 
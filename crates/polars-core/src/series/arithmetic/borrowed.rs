@@ -1,3 +1,6 @@
+#[cfg(feature = "dtype-array")]
+use arrow::compute::utils::combine_validities_and;
+
 use super::*;
 use crate::utils::align_chunks_binary;
 
@@ -117,8 +120,9 @@ impl NumOpsDispatchInner for BooleanType {
 #[cfg(feature = "dtype-array")]
 fn array_shape(dt: &DataType, infer: bool) -> Vec<i64> {
     fn inner(dt: &DataType, buf: &mut Vec<i64>) {
-        if let DataType::Array(_, size) = dt {
-            buf.push(*size as i64)
+        if let DataType::Array(inner_dt, size) = dt {
+            buf.push(*size as i64);
+            inner(inner_dt, buf);
         }
     }
 
@@ -137,37 +141,55 @@ impl ArrayChunked {
         rhs: &Series,
         op: &dyn Fn(Series, Series) -> PolarsResult<Series>,
     ) -> PolarsResult<Series> {
-        let l_leaf_array = self.clone().into_series().get_leaf_array();
         let shape = array_shape(self.dtype(), true);
 
-        let r_leaf_array = if rhs.dtype().is_numeric() && rhs.len() == 1 {
-            rhs.clone()
+        let (lhs, r_leaf_array, validity) = if rhs.dtype().is_numeric() && rhs.len() == 1 {
+            let lhs = self.rechunk();
+            let validity = lhs.chunks()[0].validity().cloned();
+            (lhs, rhs.clone(), validity)
         } else {
             polars_ensure!(self.dtype() == rhs.dtype(), InvalidOperation: "can only do arithmetic of array's of the same type and shape; got {} and {}", self.dtype(), rhs.dtype());
-            rhs.get_leaf_array()
+            let rhs = rhs.array()?;
+            // Broadcast unit length arrays so that both leaf arrays and validities line up.
+            let (lhs, rhs) = match (self.len(), rhs.len()) {
+                (1, len) if len != 1 => (self.new_from_index(0, len), rhs.rechunk()),
+                (len, 1) if len != 1 => (self.rechunk(), rhs.new_from_index(0, len)),
+                (l_len, r_len) => {
+                    polars_ensure!(l_len == r_len, ShapeMismatch: "cannot do arithmetic on arrays of length {} and {}", l_len, r_len);
+                    (self.rechunk(), rhs.rechunk())
+                },
+            };
+            let validity =
+                combine_validities_and(lhs.chunks()[0].validity(), rhs.chunks()[0].validity());
+            (lhs, rhs.into_series().get_leaf_array(), validity)
         };
+        let l_leaf_array = lhs.into_series().get_leaf_array();
 
-        let out = op(l_leaf_array, r_leaf_array)?;
-        out.reshape_array(&shape)
+        let out = op(l_leaf_array, r_leaf_array)?.reshape_array(&shape)?;
+        // The leaf arrays don't know about the outer validity, so restore it here.
+        let arr = out.chunks()[0].with_validity(validity);
+        Ok(unsafe {
+            Series::from_chunks_and_dtype_unchecked(out.name().clone(), vec![arr], out.dtype())
+        })
     }
 }
 
 #[cfg(feature = "dtype-array")]
 impl NumOpsDispatchInner for FixedSizeListType {
     fn add_to(lhs: &ArrayChunked, rhs: &Series) -> PolarsResult<Series> {
-        lhs.arithm_helper(rhs, &|l, r| l.add_to(&r))
+        lhs.arithm_helper(rhs, &|l, r| &l + &r)
     }
     fn subtract(lhs: &ArrayChunked, rhs: &Series) -> PolarsResult<Series> {
-        lhs.arithm_helper(rhs, &|l, r| l.subtract(&r))
+        lhs.arithm_helper(rhs, &|l, r| &l - &r)
     }
     fn multiply(lhs: &ArrayChunked, rhs: &Series) -> PolarsResult<Series> {
-        lhs.arithm_helper(rhs, &|l, r| l.multiply(&r))
+        lhs.arithm_helper(rhs, &|l, r| &l * &r)
     }
     fn divide(lhs: &ArrayChunked, rhs: &Series) -> PolarsResult<Series> {
-        lhs.arithm_helper(rhs, &|l, r| l.divide(&r))
+        lhs.arithm_helper(rhs, &|l, r| &l / &r)
     }
     fn remainder(lhs: &ArrayChunked, rhs: &Series) -> PolarsResult<Series> {
-        lhs.arithm_helper(rhs, &|l, r| l.remainder(&r))
+        lhs.arithm_helper(rhs, &|l, r| &l % &r)
     }
 }
 
@@ -373,6 +395,11 @@ pub(crate) fn coerce_lhs_rhs<'a>(
         (DataType::Struct(_), DataType::Struct(_)) => {
             return Ok((Cow::Borrowed(lhs), Cow::Borrowed(rhs)))
         },
+        // Numeric scalars are broadcast over the leaf values of the array.
+        #[cfg(feature = "dtype-array")]
+        (DataType::Array(..), dt) if dt.is_numeric() && rhs.len() == 1 => {
+            return Ok((Cow::Borrowed(lhs), Cow::Borrowed(rhs)))
+        },
         _ => try_get_supertype(lhs.dtype(), rhs.dtype())?,
     };
 
@@ -956,6 +983,36 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn test_arithmetic_scalar_nulls_and_zero() {
+        let s = Series::new("foo".into(), [Some(4i32), None, Some(-3)]);
+        // The validity is kept when broadcasting a scalar.
+        assert_eq!(
+            Vec::from((&s + 1).i32().unwrap()),
+            [Some(5), None, Some(-2)]
+        );
+        assert_eq!(
+            Vec::from((&s * 2).i32().unwrap()),
+            [Some(8), None, Some(-6)]
+        );
+
+        // Integer division and remainder by zero produce nulls.
+        assert_eq!(Vec::from((&s / 0).i32().unwrap()), [None, None, None]);
+        assert_eq!(Vec::from((&s % 0).i32().unwrap()), [None, None, None]);
+        let zeros = Series::new("foo".into(), [1i32, 0, 2]);
+        assert_eq!(
+            Vec::from((6.div(&zeros)).i32().unwrap()),
+            [Some(6), None, Some(3)]
+        );
+
+        // Float division by zero follows IEEE 754.
+        let s = Series::new("foo".into(), [Some(1.0f64), None, Some(-1.0)]);
+        assert_eq!(
+            Vec::from((&s / 0.0).f64().unwrap()),
+            [Some(f64::INFINITY), None, Some(f64::NEG_INFINITY)]
+        );
+    }
+
     #[test]
     #[cfg(feature = "checked_arithmetic")]
     fn test_checked_div() {
@@ -300,6 +300,7 @@ impl SeriesTrait for NullChunked {
     }
 
     fn extend(&mut self, other: &Series) -> PolarsResult<()> {
+        polars_ensure!(other.dtype() == &DataType::Null, ComputeError: "expected null dtype");
         *self = NullChunked::new(self.name.clone(), self.len() + other.len());
         Ok(())
     }
@@ -123,8 +123,9 @@ where
         Ok(())
     }
 
-    fn extend(&mut self, _other: &Series) -> PolarsResult<()> {
-        polars_bail!(opq = extend, self.dtype());
+    fn extend(&mut self, other: &Series) -> PolarsResult<()> {
+        // Objects have no contiguous buffer to grow, so extending appends the chunks.
+        self.append(other)
     }
 
     fn filter(&self, filter: &BooleanChunked) -> PolarsResult<Series> {
@@ -237,15 +238,14 @@ where
 mod test {
     use super::*;
 
-    #[test]
-    fn test_downcast_object() -> PolarsResult<()> {
-        #[allow(non_local_definitions)]
-        impl PolarsObject for i32 {
-            fn type_name() -> &'static str {
-                "i32"
-            }
+    impl PolarsObject for i32 {
+        fn type_name() -> &'static str {
+            "i32"
         }
+    }
 
+    #[test]
+    fn test_downcast_object() -> PolarsResult<()> {
         let ca = ObjectChunked::new_from_vec("a".into(), vec![0i32, 1, 2]);
         let s = ca.into_series();
 
@@ -256,4 +256,28 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn test_object_filter_take_extend() -> PolarsResult<()> {
+        let mut s = ObjectChunked::new_from_vec("a".into(), vec![0i32, 1, 2]).into_series();
+        let other = ObjectChunked::new_from_vec("a".into(), vec![3i32]).into_series();
+        s.extend(&other)?;
+        assert_eq!(s.len(), 4);
+
+        let mask = BooleanChunked::new("".into(), [true, false, false, true]);
+        let filtered = s.filter(&mask)?;
+        let ca = filtered
+            .as_any()
+            .downcast_ref::<ObjectChunked<i32>>()
+            .unwrap();
+        assert_eq!(ca.len(), 2);
+        assert_eq!(*ca.get(0).unwrap(), 0);
+        assert_eq!(*ca.get(1).unwrap(), 3);
+
+        let taken = s.take_slice(&[2, 1])?;
+        let ca = taken.as_any().downcast_ref::<ObjectChunked<i32>>().unwrap();
+        assert_eq!(*ca.get(0).unwrap(), 2);
+        assert_eq!(*ca.get(1).unwrap(), 1);
+        Ok(())
+    }
 }
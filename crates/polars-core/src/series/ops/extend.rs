@@ -4,7 +4,7 @@ impl Series {
     /// Extend with a constant value.
     pub fn extend_constant(&self, value: AnyValue, n: usize) -> PolarsResult<Self> {
         // TODO: Use `from_any_values_and_dtype` here instead of casting afterwards
-        let s = Series::from_any_values(PlSmallStr::EMPTY, &[value], true).unwrap();
+        let s = Series::from_any_values(PlSmallStr::EMPTY, &[value], true)?;
         let s = s.cast(self.dtype())?;
         let to_append = s.new_from_index(0, n);
 
@@ -13,3 +13,22 @@ impl Series {
         Ok(out)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_extend_constant_null_padding() -> PolarsResult<()> {
+        let s = Series::new("a".into(), &[1i32, 2]);
+
+        let out = s.extend_constant(AnyValue::Null, 2)?;
+        assert_eq!(out.name().as_str(), "a");
+        assert_eq!(out.dtype(), &DataType::Int32);
+        assert_eq!(Vec::from(out.i32()?), &[Some(1), Some(2), None, None]);
+
+        let out = s.extend_constant(AnyValue::Int64(7), 0)?;
+        assert!(out.equals(&s));
+        Ok(())
+    }
+}
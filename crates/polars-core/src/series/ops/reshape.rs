@@ -11,7 +11,6 @@ use polars_error::{polars_bail, polars_ensure, PolarsResult};
 #[cfg(feature = "dtype-array")]
 use polars_utils::format_tuple;
 
-use crate::chunked_array::builder::get_list_builder;
 use crate::datatypes::{DataType, ListChunked};
 use crate::prelude::{IntoSeries, Series, *};
 
@@ -231,16 +230,27 @@ impl Series {
                     InvalidOperation: "cannot reshape len {} into shape {:?}", s_ref.len(), dimensions,
                 );
 
-                let mut builder =
-                    get_list_builder(s_ref.dtype(), s_ref.len(), rows as usize, s.name().clone())?;
-
-                let mut offset = 0i64;
-                for _ in 0..rows {
-                    let row = s_ref.slice(offset, cols as usize);
-                    builder.append_series(&row).unwrap();
-                    offset += cols;
-                }
-                Ok(builder.finish().into_series())
+                // Every sublist has the same length, so we can point fixed offsets into the
+                // values instead of copying them row by row.
+                let s = s_ref.rechunk();
+                let values = s.array_ref(0);
+                let offsets = (0..=rows).map(|i| i * cols).collect::<Vec<_>>();
+                let dtype = ListArray::<i64>::default_datatype(values.dtype().clone());
+
+                // SAFETY: offsets are monotonically increasing and end at `values.len()`.
+                let arr = unsafe {
+                    ListArray::new(
+                        dtype,
+                        Offsets::new_unchecked(offsets).into(),
+                        values.clone(),
+                        None,
+                    )
+                };
+
+                let mut ca = ListChunked::with_chunk(s.name().clone(), arr);
+                unsafe { ca.to_logical(s.dtype().clone()) };
+                ca.set_fast_explode();
+                Ok(ca.into_series())
             },
             _ => {
                 polars_bail!(InvalidOperation: "more than two dimensions not supported in reshaping to List.\n\nConsider reshaping to Array type.");
@@ -252,6 +262,7 @@ impl Series {
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::chunked_array::builder::get_list_builder;
     use crate::prelude::*;
 
     #[test]
@@ -287,4 +298,53 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn test_reshape_list_roundtrip() -> PolarsResult<()> {
+        let s = Series::new("a".into(), &[1, 2, 3, 4, 5, 6]);
+
+        let out = s.reshape_list(&[-1, 3])?;
+        assert_eq!(out.dtype(), &DataType::List(Box::new(DataType::Int32)));
+        assert_eq!(out.name().as_str(), "a");
+        let rows = out.list()?;
+        assert_eq!(rows.len(), 2);
+        assert!(rows
+            .get_as_series(0)
+            .unwrap()
+            .equals(&Series::new("".into(), &[1, 2, 3])));
+        assert!(rows
+            .get_as_series(1)
+            .unwrap()
+            .equals(&Series::new("".into(), &[4, 5, 6])));
+
+        // A sliced input only exposes its own values.
+        let out = s.slice(2, 4).reshape_list(&[2, -1])?;
+        assert!(out
+            .explode()?
+            .equals(&Series::new("a".into(), &[3, 4, 5, 6])));
+
+        // And back to a flat Series.
+        let flat = out.reshape_list(&[-1])?;
+        assert_eq!(flat.dtype(), &DataType::Int32);
+        assert!(flat.equals(&Series::new("a".into(), &[3, 4, 5, 6])));
+
+        let s = Series::new("a".into(), &["x", "y", "z", "w"]);
+        let out = s.reshape_list(&[2, 2])?;
+        assert_eq!(out.dtype(), &DataType::List(Box::new(DataType::String)));
+        assert!(out.explode()?.equals(&s));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_reshape_list_invalid_dims() {
+        let s = Series::new("a".into(), &[1, 2, 3, 4, 5]);
+
+        assert!(s.reshape_list(&[]).is_err());
+        assert!(s.reshape_list(&[4]).is_err());
+        assert!(s.reshape_list(&[-1, 2]).is_err());
+        assert!(s.reshape_list(&[2, 2]).is_err());
+        assert!(s.reshape_list(&[0, -1]).is_err());
+        assert!(s.reshape_list(&[1, 1, 5]).is_err());
+    }
 }
@@ -15,3 +15,15 @@ fn test_initial_empty_sort() -> PolarsResult<()> {
     series.f64()?.sort(false);
     Ok(())
 }
+
+#[test]
+#[cfg(not(target_family = "wasm"))]
+fn test_set_num_threads() {
+    assert!(crate::set_num_threads(0).is_err());
+
+    // The pool can't be resized once it is running.
+    let n = crate::thread_pool_size();
+    assert!(n > 0);
+    assert!(crate::set_num_threads(n + 1).is_err());
+    assert_eq!(crate::thread_pool_size(), n);
+}
@@ -402,6 +402,72 @@ where
         })
 }
 
+/// Given two numeric data types, determine the data type that both types can be cast to
+/// without losing any values.
+///
+/// Unlike [`get_supertype`], this never picks a type that loses precision, e.g. there is
+/// no lossless supertype of `Int64` and `UInt64`, or of `Int64` and `Float64`.
+pub fn get_numeric_upcast_supertype_lossless(l: &DataType, r: &DataType) -> Option<DataType> {
+    use DataType::*;
+
+    // (is_signed, bits) of a concrete integer type.
+    fn int_info(dt: &DataType) -> Option<(bool, u8)> {
+        match dt {
+            Int8 => Some((true, 8)),
+            Int16 => Some((true, 16)),
+            Int32 => Some((true, 32)),
+            Int64 => Some((true, 64)),
+            UInt8 => Some((false, 8)),
+            UInt16 => Some((false, 16)),
+            UInt32 => Some((false, 32)),
+            UInt64 => Some((false, 64)),
+            _ => None,
+        }
+    }
+
+    fn signed_int(bits: u8) -> Option<DataType> {
+        match bits {
+            8 => Some(Int8),
+            16 => Some(Int16),
+            32 => Some(Int32),
+            64 => Some(Int64),
+            _ => None,
+        }
+    }
+
+    if l == r {
+        return (l.is_integer() || l.is_float()).then(|| l.clone());
+    }
+
+    match (l, r) {
+        (Float32 | Float64, Float32 | Float64) => Some(Float64),
+        (Float32 | Float64, int) | (int, Float32 | Float64) => {
+            // A float represents integers exactly up to the size of its mantissa.
+            let (_, bits) = int_info(int)?;
+            let float = if matches!(l, Float32) || matches!(r, Float32) {
+                Float32
+            } else {
+                Float64
+            };
+            match (bits, float) {
+                (..=16, float) => Some(float),
+                (32, _) => Some(Float64),
+                _ => None,
+            }
+        },
+        _ => {
+            let (l_signed, l_bits) = int_info(l)?;
+            let (r_signed, r_bits) = int_info(r)?;
+            match (l_signed, r_signed) {
+                _ if l_signed == r_signed => Some(if l_bits > r_bits { l } else { r }.clone()),
+                (true, false) if l_bits > r_bits => Some(l.clone()),
+                (false, true) if r_bits > l_bits => Some(r.clone()),
+                _ => signed_int(2 * l_bits.max(r_bits)),
+            }
+        },
+    }
+}
+
 #[cfg(feature = "dtype-struct")]
 fn union_struct_fields(fields_a: &[Field], fields_b: &[Field]) -> Option<DataType> {
     let (longest, shortest) = {
@@ -92,6 +92,7 @@ pub struct JsonWriterOptions {
 ///
 /// In either case, each row is serialized as a JSON object whose keys are the column names and
 /// whose values are the row's corresponding values.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum JsonFormat {
     /// A single JSON array containing each DataFrame row as an object. The length of the array is the number of rows in
     /// the DataFrame.
@@ -293,6 +294,17 @@ where
                 } else {
                     // infer
                     let inner_dtype = if let BorrowedValue::Array(values) = &json_value {
+                        // An empty array holds no records, so there are no columns to infer.
+                        if values.is_empty() {
+                            let df = self
+                                .schema_overwrite
+                                .map(DataFrame::empty_with_schema)
+                                .unwrap_or_default();
+                            return match self.projection.as_deref() {
+                                Some(proj) => df.select(proj.iter().cloned()),
+                                None => Ok(df),
+                            };
+                        }
                         infer::json_values_to_supertype(
                             values,
                             self.infer_schema_len
@@ -6,9 +6,9 @@ use polars_core::prelude::*;
 use polars_core::POOL;
 use polars_parquet::read::ParquetError;
 use polars_parquet::write::{
-    array_to_columns, CompressedPage, Compressor, DynIter, DynStreamingIterator, Encoding,
-    FallibleStreamingIterator, FileWriter, Page, ParquetType, RowGroupIterColumns,
-    SchemaDescriptor, WriteOptions,
+    array_to_columns, CompressedPage, CompressionOptions, Compressor, DynIter,
+    DynStreamingIterator, Encoding, FallibleStreamingIterator, FileWriter, Page, ParquetType,
+    RowGroupIterColumns, SchemaDescriptor, WriteOptions,
 };
 use rayon::prelude::*;
 
@@ -18,6 +18,8 @@ pub struct BatchedWriter<W: Write> {
     pub(super) writer: Mutex<FileWriter<W>>,
     pub(super) parquet_schema: SchemaDescriptor,
     pub(super) encodings: Vec<Vec<Encoding>>,
+    /// The compression of every column.
+    pub(super) compressions: Vec<CompressionOptions>,
     pub(super) options: WriteOptions,
     pub(super) parallel: bool,
 }
@@ -35,6 +37,7 @@ impl<W: Write> BatchedWriter<W> {
                     batch,
                     self.parquet_schema.fields(),
                     self.encodings.as_ref(),
+                    self.compressions.as_ref(),
                     self.options,
                 );
 
@@ -52,6 +55,7 @@ impl<W: Write> BatchedWriter<W> {
             df,
             &self.parquet_schema,
             &self.encodings,
+            &self.compressions,
             self.options,
             self.parallel,
         );
@@ -92,6 +96,7 @@ fn prepare_rg_iter<'a>(
     df: &'a DataFrame,
     parquet_schema: &'a SchemaDescriptor,
     encodings: &'a [Vec<Encoding>],
+    compressions: &'a [CompressionOptions],
     options: WriteOptions,
     parallel: bool,
 ) -> impl Iterator<Item = PolarsResult<RowGroupIterColumns<'static, PolarsError>>> + 'a {
@@ -99,8 +104,14 @@ fn prepare_rg_iter<'a>(
     rb_iter.filter_map(move |batch| match batch.len() {
         0 => None,
         _ => {
-            let row_group =
-                create_serializer(batch, parquet_schema.fields(), encodings, options, parallel);
+            let row_group = create_serializer(
+                batch,
+                parquet_schema.fields(),
+                encodings,
+                compressions,
+                options,
+                parallel,
+            );
 
             Some(row_group)
         },
@@ -147,10 +158,18 @@ fn create_serializer(
     batch: RecordBatch,
     fields: &[ParquetType],
     encodings: &[Vec<Encoding>],
+    compressions: &[CompressionOptions],
     options: WriteOptions,
     parallel: bool,
 ) -> PolarsResult<RowGroupIterColumns<'static, PolarsError>> {
-    let func = move |((array, type_), encoding): ((&ArrayRef, &ParquetType), &Vec<Encoding>)| {
+    let func = move |(((array, type_), encoding), compression): (
+        ((&ArrayRef, &ParquetType), &Vec<Encoding>),
+        &CompressionOptions,
+    )| {
+        let options = WriteOptions {
+            compression: *compression,
+            ..options
+        };
         array_to_pages_iter(array, type_, encoding, options)
     };
 
@@ -161,6 +180,7 @@ fn create_serializer(
                 .par_iter()
                 .zip(fields)
                 .zip(encodings)
+                .zip(compressions)
                 .flat_map(func)
                 .collect::<Vec<_>>()
         })
@@ -170,6 +190,7 @@ fn create_serializer(
             .iter()
             .zip(fields)
             .zip(encodings)
+            .zip(compressions)
             .flat_map(func)
             .collect::<Vec<_>>()
     };
@@ -185,9 +206,17 @@ fn create_eager_serializer(
     batch: RecordBatch,
     fields: &[ParquetType],
     encodings: &[Vec<Encoding>],
+    compressions: &[CompressionOptions],
     options: WriteOptions,
 ) -> PolarsResult<RowGroupIterColumns<'static, PolarsError>> {
-    let func = move |((array, type_), encoding): ((&ArrayRef, &ParquetType), &Vec<Encoding>)| {
+    let func = move |(((array, type_), encoding), compression): (
+        ((&ArrayRef, &ParquetType), &Vec<Encoding>),
+        &CompressionOptions,
+    )| {
+        let options = WriteOptions {
+            compression: *compression,
+            ..options
+        };
         array_to_pages_iter(array, type_, encoding, options)
     };
 
@@ -196,6 +225,7 @@ fn create_eager_serializer(
         .iter()
         .zip(fields)
         .zip(encodings)
+        .zip(compressions)
         .flat_map(func)
         .collect::<Vec<_>>();
 
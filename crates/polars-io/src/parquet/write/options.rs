@@ -3,10 +3,11 @@ use polars_parquet::write::{
     BrotliLevel as BrotliLevelParquet, CompressionOptions, GzipLevel as GzipLevelParquet,
     StatisticsOptions, ZstdLevel as ZstdLevelParquet,
 };
+use polars_utils::pl_str::PlSmallStr;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-#[derive(Copy, Clone, Debug, PartialEq, Eq, Default, Hash)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ParquetWriteOptions {
     /// Data page compression
@@ -19,6 +20,24 @@ pub struct ParquetWriteOptions {
     pub data_page_size: Option<usize>,
     /// maintain the order the data was processed
     pub maintain_order: bool,
+    /// Allow dictionary encoding of columns other than categoricals.
+    pub dictionary_encoding: bool,
+    /// Overrides of `compression` for individual top-level columns.
+    pub column_compression: Vec<(PlSmallStr, ParquetCompression)>,
+}
+
+impl Default for ParquetWriteOptions {
+    fn default() -> Self {
+        Self {
+            compression: ParquetCompression::default(),
+            statistics: StatisticsOptions::default(),
+            row_group_size: None,
+            data_page_size: None,
+            maintain_order: false,
+            dictionary_encoding: true,
+            column_compression: vec![],
+        }
+    }
 }
 
 /// The compression strategy to use for writing Parquet files.
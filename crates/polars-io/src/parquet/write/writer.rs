@@ -24,6 +24,8 @@ impl ParquetWriteOptions {
             .with_statistics(self.statistics)
             .with_row_group_size(self.row_group_size)
             .with_data_page_size(self.data_page_size)
+            .with_dictionary_encoding(self.dictionary_encoding)
+            .with_column_compression(self.column_compression.iter().cloned().collect())
     }
 }
 
@@ -41,6 +43,10 @@ pub struct ParquetWriter<W> {
     data_page_size: Option<usize>,
     /// Serialize columns in parallel
     parallel: bool,
+    /// Allow dictionary encoding of columns other than categoricals.
+    dictionary_encoding: bool,
+    /// Overrides of `compression` for individual columns.
+    column_compression: PlHashMap<PlSmallStr, ParquetCompression>,
 }
 
 impl<W> ParquetWriter<W>
@@ -59,6 +65,8 @@ where
             row_group_size: None,
             data_page_size: None,
             parallel: true,
+            dictionary_encoding: true,
+            column_compression: PlHashMap::new(),
         }
     }
 
@@ -96,10 +104,43 @@ where
         self
     }
 
+    /// Toggle dictionary encoding. Defaults to `true`.
+    ///
+    /// If disabled, all columns are written with plain encoding, except categoricals which are
+    /// always dictionary encoded.
+    pub fn with_dictionary_encoding(mut self, enabled: bool) -> Self {
+        self.dictionary_encoding = enabled;
+        self
+    }
+
+    /// Set the compression of individual columns. Columns that are not given use the compression
+    /// set with [`ParquetWriter::with_compression`].
+    ///
+    /// The names refer to top-level columns only; all leaves of a nested column share its
+    /// compression.
+    pub fn with_column_compression(
+        mut self,
+        column_compression: PlHashMap<PlSmallStr, ParquetCompression>,
+    ) -> Self {
+        self.column_compression = column_compression;
+        self
+    }
+
     pub fn batched(self, schema: &Schema) -> PolarsResult<BatchedWriter<W>> {
+        for name in self.column_compression.keys() {
+            polars_ensure!(schema.contains(name), ColumnNotFound: "{}", name);
+        }
+        let compressions = schema
+            .iter_names()
+            .map(|name| match self.column_compression.get(name) {
+                Some(compression) => (*compression).into(),
+                None => self.compression,
+            })
+            .collect();
+
         let schema = schema_to_arrow_checked(schema, CompatLevel::newest(), "parquet")?;
         let parquet_schema = to_parquet_schema(&schema)?;
-        let encodings = get_encodings(&schema);
+        let encodings = get_encodings(&schema, self.dictionary_encoding);
         let options = self.materialize_options();
         let writer = Mutex::new(FileWriter::try_new(self.writer, schema, options)?);
 
@@ -107,6 +148,7 @@ where
             writer,
             parquet_schema,
             encodings,
+            compressions,
             options,
             parallel: self.parallel,
         })
@@ -123,29 +165,32 @@ where
 
     /// Write the given DataFrame in the writer `W`. Returns the total size of the file.
     pub fn finish(self, df: &mut DataFrame) -> PolarsResult<u64> {
-        let chunked_df = chunk_df_for_writing(df, self.row_group_size.unwrap_or(512 * 512))?;
+        let row_group_size = self.row_group_size.unwrap_or(512 * 512);
+        polars_ensure!(row_group_size > 0, InvalidOperation: "'row_group_size' must be greater than 0");
+        let chunked_df = chunk_df_for_writing(df, row_group_size)?;
         let mut batched = self.batched(&chunked_df.schema())?;
         batched.write_batch(&chunked_df)?;
         batched.finish()
     }
 }
 
-fn get_encodings(schema: &ArrowSchema) -> Vec<Vec<Encoding>> {
+fn get_encodings(schema: &ArrowSchema, dictionary_encoding: bool) -> Vec<Vec<Encoding>> {
     schema
         .iter_values()
-        .map(|f| transverse(&f.dtype, encoding_map))
+        .map(|f| transverse(&f.dtype, |dtype| encoding_map(dtype, dictionary_encoding)))
         .collect()
 }
 
 /// Declare encodings
-fn encoding_map(dtype: &ArrowDataType) -> Encoding {
+fn encoding_map(dtype: &ArrowDataType, dictionary_encoding: bool) -> Encoding {
     match dtype.to_physical_type() {
-        PhysicalType::Dictionary(_)
-        | PhysicalType::LargeBinary
+        PhysicalType::Dictionary(_) => Encoding::RleDictionary,
+        PhysicalType::Boolean => Encoding::Rle,
+        _ if !dictionary_encoding => Encoding::Plain,
+        PhysicalType::LargeBinary
         | PhysicalType::LargeUtf8
         | PhysicalType::Utf8View
         | PhysicalType::BinaryView => Encoding::RleDictionary,
-        PhysicalType::Boolean => Encoding::Rle,
         PhysicalType::Primitive(dt) => {
             use arrow::types::PrimitiveType::*;
             match dt {
@@ -5,7 +5,7 @@ use std::io::Read;
 use once_cell::sync::Lazy;
 use polars_core::prelude::*;
 #[cfg(any(feature = "ipc_streaming", feature = "parquet"))]
-use polars_core::utils::{accumulate_dataframes_vertical_unchecked, split_df_as_ref};
+use polars_core::utils::accumulate_dataframes_vertical_unchecked;
 use polars_utils::mmap::MMapSemaphore;
 use regex::{Regex, RegexBuilder};
 
@@ -139,11 +139,13 @@ pub fn overwrite_schema(schema: &mut Schema, overwriting_schema: &Schema) -> Pol
 }
 
 pub static FLOAT_RE: Lazy<Regex> = Lazy::new(|| {
-    Regex::new(r"^[-+]?((\d*\.\d+)([eE][-+]?\d+)?|inf|NaN|(\d+)[eE][-+]?\d+|\d+\.)$").unwrap()
+    Regex::new(r"^[-+]?((\d*\.\d+)([eE][-+]?\d+)?|(?i:inf|infinity|nan)|(\d+)[eE][-+]?\d+|\d+\.)$")
+        .unwrap()
 });
 
 pub static FLOAT_RE_DECIMAL: Lazy<Regex> = Lazy::new(|| {
-    Regex::new(r"^[-+]?((\d*,\d+)([eE][-+]?\d+)?|inf|NaN|(\d+)[eE][-+]?\d+|\d+,)$").unwrap()
+    Regex::new(r"^[-+]?((\d*,\d+)([eE][-+]?\d+)?|(?i:inf|infinity|nan)|(\d+)[eE][-+]?\d+|\d+,)$")
+        .unwrap()
 });
 
 pub static INTEGER_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"^-?(\d+)$").unwrap());
@@ -215,28 +217,47 @@ pub(crate) fn chunk_df_for_writing(
 
         let mut new_chunks = Vec::with_capacity(df.n_chunks()); // upper limit;
         let mut scratch = vec![];
-        let mut remaining = row_group_size;
+        let mut scratch_height = 0;
 
         for df in df.split_chunks() {
-            remaining = remaining.saturating_sub(df.height());
-            scratch.push(df);
+            // Later chunks may still be larger than a row group.
+            let mut offset = 0;
+            while offset < df.height() {
+                let df = df.slice(offset as i64, row_group_size);
+                offset += df.height();
 
-            if remaining == 0 {
-                remaining = row_group_size;
-                finish(&mut scratch, &mut new_chunks);
+                // Flush first so that merging never exceeds `row_group_size`.
+                if scratch_height + df.height() > row_group_size {
+                    scratch_height = 0;
+                    finish(&mut scratch, &mut new_chunks);
+                }
+                scratch_height += df.height();
+                scratch.push(df);
+
+                if scratch_height == row_group_size {
+                    scratch_height = 0;
+                    finish(&mut scratch, &mut new_chunks);
+                }
             }
         }
         if !scratch.is_empty() {
             finish(&mut scratch, &mut new_chunks);
         }
+        // Only empty chunks, there is nothing to merge.
+        if new_chunks.is_empty() {
+            return Ok(Cow::Borrowed(df));
+        }
         return Ok(Cow::Owned(accumulate_dataframes_vertical_unchecked(
             new_chunks,
         )));
     }
 
-    let n_splits = df.height() / row_group_size;
-    let result = if n_splits > 0 {
-        let mut splits = split_df_as_ref(df, n_splits, false);
+    let n_splits = df.height().div_ceil(row_group_size);
+    let result = if n_splits > 1 {
+        // Slice at exact multiples so that no row group exceeds `row_group_size`.
+        let mut splits = (0..n_splits)
+            .map(|i| df.slice((i * row_group_size) as i64, row_group_size))
+            .collect::<Vec<_>>();
 
         for df in splits.iter_mut() {
             // If the chunks are small enough, writing many small chunks
@@ -278,5 +299,11 @@ mod tests {
         assert!(FLOAT_RE.is_match("-7e-05"));
         assert!(FLOAT_RE.is_match("7e-05"));
         assert!(FLOAT_RE.is_match("+7e+05"));
+        assert!(FLOAT_RE.is_match("nan"));
+        assert!(FLOAT_RE.is_match("+inf"));
+        assert!(FLOAT_RE.is_match("-Infinity"));
+        assert!(FLOAT_RE.is_match("INF"));
+        assert!(!FLOAT_RE.is_match("info"));
+        assert!(!FLOAT_RE.is_match("nana"));
     }
 }
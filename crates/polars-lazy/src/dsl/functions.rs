@@ -88,6 +88,9 @@ pub fn concat<L: AsRef<[LazyFrame]>>(inputs: L, args: UnionArgs) -> PolarsResult
 }
 
 /// Collect all [`LazyFrame`] computations.
+///
+/// The queries are executed concurrently on the global thread pool and the
+/// results are returned in the order of the input.
 pub fn collect_all<I>(lfs: I) -> PolarsResult<Vec<DataFrame>>
 where
     I: IntoParallelIterator<Item = LazyFrame>,
@@ -99,10 +102,30 @@ where
 
 #[cfg(test)]
 mod test {
-    // used only if feature="diagonal_concat"
-    #[allow(unused_imports)]
     use super::*;
 
+    #[test]
+    fn test_collect_all_keeps_order() -> PolarsResult<()> {
+        let df = df![
+            "a" => [1, 2, 3]
+        ]?;
+
+        let lfs = (0..4)
+            .map(|i| df.clone().lazy().select([(col("a") * lit(i)).alias("a")]))
+            .collect::<Vec<_>>();
+        let out = collect_all(lfs)?;
+
+        assert_eq!(out.len(), 4);
+        for (i, df) in out.iter().enumerate() {
+            let i = i as i32;
+            assert_eq!(
+                Vec::from(df.column("a")?.i32()?),
+                &[Some(i), Some(2 * i), Some(3 * i)]
+            );
+        }
+        Ok(())
+    }
+
     #[test]
     #[cfg(feature = "diagonal_concat")]
     fn test_diag_concat_lf() -> PolarsResult<()> {
@@ -1,6 +1,7 @@
 use std::sync::atomic::{AtomicBool, Ordering};
-use std::sync::mpsc::{channel, Receiver};
+use std::sync::mpsc::{channel, Receiver, RecvTimeoutError};
 use std::sync::Mutex;
+use std::time::Duration;
 
 use polars_core::POOL;
 
@@ -50,6 +51,18 @@ impl InProcessQuery {
         let rx = self.rx.lock().unwrap();
         rx.recv().unwrap()
     }
+
+    /// Await the result for at most `timeout`.
+    ///
+    /// If the result is not ready in time it will return `None`.
+    pub fn fetch_timeout(&self, timeout: Duration) -> Option<PolarsResult<DataFrame>> {
+        let rx = self.rx.lock().unwrap();
+        match rx.recv_timeout(timeout) {
+            Ok(out) => Some(out),
+            Err(RecvTimeoutError::Timeout) => None,
+            Err(RecvTimeoutError::Disconnected) => panic!("query stopped without a result"),
+        }
+    }
 }
 
 impl Drop for InProcessQuery {
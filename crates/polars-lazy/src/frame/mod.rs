@@ -1335,7 +1335,9 @@ impl LazyFrame {
             .how(args.how)
             .validate(args.validation)
             .coalesce(args.coalesce)
-            .join_nulls(args.join_nulls);
+            .join_nulls(args.join_nulls)
+            .coerce_keys(args.coerce_keys)
+            .algorithm(args.algorithm);
 
         if let Some(suffix) = args.suffix {
             builder = builder.suffix(suffix);
@@ -1431,6 +1433,11 @@ impl LazyFrame {
         Self::from_logical_plan(lp, opt_state)
     }
 
+    /// Add external contexts to the computation graph.
+    ///
+    /// Expressions on the returned [`LazyFrame`] can refer to the columns of `contexts`, e.g. to
+    /// use a scalar computed in another frame, without joining the frames. The context columns are
+    /// not part of the output unless they are selected.
     pub fn with_context<C: AsRef<[LazyFrame]>>(self, contexts: C) -> LazyFrame {
         let contexts = contexts
             .as_ref()
@@ -1975,6 +1982,8 @@ pub struct JoinBuilder {
     validation: JoinValidation,
     coalesce: JoinCoalesce,
     join_nulls: bool,
+    coerce_keys: bool,
+    algorithm: JoinAlgorithm,
 }
 impl JoinBuilder {
     /// Create the `JoinBuilder` with the provided `LazyFrame` as the left table.
@@ -1988,6 +1997,8 @@ impl JoinBuilder {
             allow_parallel: true,
             force_parallel: false,
             join_nulls: false,
+            coerce_keys: true,
+            algorithm: Default::default(),
             suffix: None,
             validation: Default::default(),
             coalesce: Default::default(),
@@ -2055,6 +2066,20 @@ impl JoinBuilder {
         self
     }
 
+    /// Cast key columns of different but compatible dtypes to a common dtype before joining.
+    /// Enabled by default; disable it to raise on any key dtype mismatch instead.
+    pub fn coerce_keys(mut self, coerce_keys: bool) -> Self {
+        self.coerce_keys = coerce_keys;
+        self
+    }
+
+    /// Override the algorithm that is used to join on a single key.
+    /// Defaults to [`JoinAlgorithm::Auto`].
+    pub fn algorithm(mut self, algorithm: JoinAlgorithm) -> Self {
+        self.algorithm = algorithm;
+        self
+    }
+
     /// Suffix to add duplicate column names in join.
     /// Defaults to `"_right"` if this method is never called.
     pub fn suffix<S>(mut self, suffix: S) -> Self
@@ -2088,6 +2113,8 @@ impl JoinBuilder {
             slice: None,
             join_nulls: self.join_nulls,
             coalesce: self.coalesce,
+            coerce_keys: self.coerce_keys,
+            algorithm: self.algorithm,
         };
 
         let lp = self
@@ -2126,6 +2153,8 @@ impl JoinBuilder {
             slice: None,
             join_nulls: self.join_nulls,
             coalesce: self.coalesce,
+            coerce_keys: self.coerce_keys,
+            algorithm: self.algorithm,
         };
         let options = JoinOptions {
             allow_parallel: self.allow_parallel,
@@ -7,7 +7,7 @@ pub use polars_io::ipc::IpcWriterOptions;
 pub use polars_io::json::JsonWriterOptions;
 #[cfg(feature = "parquet")]
 pub use polars_io::parquet::write::ParquetWriteOptions;
-pub use polars_ops::prelude::{JoinArgs, JoinType, JoinValidation};
+pub use polars_ops::prelude::{JoinAlgorithm, JoinArgs, JoinType, JoinValidation};
 #[cfg(feature = "rank")]
 pub use polars_ops::prelude::{RankMethod, RankOptions};
 #[cfg(feature = "polars_cloud")]
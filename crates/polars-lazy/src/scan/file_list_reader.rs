@@ -1,8 +1,14 @@
+#[cfg(any(feature = "parquet", feature = "ipc"))]
+use std::path::Path;
 use std::path::PathBuf;
 use std::sync::Arc;
 
 use polars_core::prelude::*;
 use polars_io::cloud::CloudOptions;
+#[cfg(any(feature = "parquet", feature = "ipc"))]
+use polars_io::path_utils::expand_paths_hive;
+#[cfg(any(feature = "parquet", feature = "ipc"))]
+use polars_io::HiveOptions;
 use polars_io::RowIndex;
 use polars_plan::prelude::UnionArgs;
 
@@ -121,3 +127,75 @@ pub trait LazyFileListReader: Clone {
         None
     }
 }
+
+/// Expand the paths for a scan that unifies schemas. Unifying scans every file separately,
+/// so this errors if the paths contain hive partitions, unless hive partitioning was
+/// explicitly disabled.
+#[cfg(any(feature = "parquet", feature = "ipc"))]
+pub(crate) fn expand_paths_unified(
+    paths: &[PathBuf],
+    glob: bool,
+    cloud_options: Option<&CloudOptions>,
+    hive_options: &HiveOptions,
+) -> PolarsResult<Arc<[PathBuf]>> {
+    let (paths, hive_start_idx) = expand_paths_hive(paths, glob, cloud_options, false)?;
+    if hive_options.enabled != Some(false) {
+        let is_hive_partitioned = paths.iter().any(|path| {
+            let path = path.to_str().unwrap();
+            Path::new(&path[hive_start_idx..])
+                .parent()
+                .is_some_and(|dir| {
+                    dir.components()
+                        .any(|c| c.as_os_str().to_str().is_some_and(|c| c.contains('=')))
+                })
+        });
+        polars_ensure!(
+            !is_hive_partitioned,
+            InvalidOperation: "unify_schemas cannot be combined with hive partitioning; \
+            disable hive partitioning to scan these paths"
+        );
+    }
+    Ok(paths)
+}
+
+/// Scan every path separately and concatenate the results diagonally, so that columns
+/// missing from a file are filled with nulls and differing dtypes are cast to their
+/// supertype.
+///
+/// `reader` must be configured to neither glob nor unify schemas itself.
+#[cfg(any(feature = "parquet", feature = "ipc"))]
+pub(crate) fn finish_unified<R: LazyFileListReader>(
+    reader: R,
+    paths: &[PathBuf],
+    finish_file: impl Fn(R) -> PolarsResult<LazyFrame>,
+) -> PolarsResult<LazyFrame> {
+    let lfs = paths
+        .iter()
+        .map(|path| {
+            let file_reader = reader
+                .clone()
+                .with_n_rows(None)
+                .with_row_index(None)
+                .with_paths([path.clone()].into())
+                .with_rechunk(false);
+            finish_file(file_reader)
+                .map_err(|e| e.context(format!("error while reading {}", path.display()).into()))
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+
+    let args = UnionArgs {
+        rechunk: reader.rechunk(),
+        parallel: true,
+        to_supertypes: true,
+        diagonal: true,
+        from_partitioned_ds: true,
+    };
+    let mut lf = concat_impl(&lfs, args)?;
+    if let Some(n_rows) = reader.n_rows() {
+        lf = lf.slice(0, n_rows as IdxSize)
+    };
+    if let Some(rc) = reader.row_index() {
+        lf = lf.with_row_index(rc.name.clone(), Some(rc.offset))
+    };
+    Ok(lf)
+}
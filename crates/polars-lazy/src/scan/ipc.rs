@@ -6,6 +6,7 @@ use polars_io::ipc::IpcScanOptions;
 use polars_io::{HiveOptions, RowIndex};
 
 use crate::prelude::*;
+use crate::scan::file_list_reader::{expand_paths_unified, finish_unified};
 
 #[derive(Clone)]
 pub struct ScanArgsIpc {
@@ -16,6 +17,10 @@ pub struct ScanArgsIpc {
     pub cloud_options: Option<CloudOptions>,
     pub hive_options: HiveOptions,
     pub include_file_paths: Option<PlSmallStr>,
+    /// Allow the files to have differing schemas. Columns missing from a file are
+    /// filled with nulls and differing dtypes are cast to their supertype. This
+    /// requires hive partitioning to not be enabled.
+    pub unify_schemas: bool,
 }
 
 impl Default for ScanArgsIpc {
@@ -28,6 +33,7 @@ impl Default for ScanArgsIpc {
             cloud_options: Default::default(),
             hive_options: Default::default(),
             include_file_paths: None,
+            unify_schemas: false,
         }
     }
 }
@@ -49,6 +55,23 @@ impl LazyIpcReader {
 
 impl LazyFileListReader for LazyIpcReader {
     fn finish(self) -> PolarsResult<LazyFrame> {
+        if self.args.unify_schemas {
+            if let ScanSources::Paths(paths) = &self.sources {
+                let paths = expand_paths_unified(
+                    paths,
+                    true,
+                    self.args.cloud_options.as_ref(),
+                    &self.args.hive_options,
+                )?;
+                if paths.len() > 1 {
+                    let mut reader = self;
+                    reader.args.unify_schemas = false;
+                    reader.args.hive_options.enabled = Some(false);
+                    return finish_unified(reader, &paths, |r| r.finish());
+                }
+            }
+        }
+
         let args = self.args;
 
         let options = IpcScanOptions {};
@@ -6,6 +6,7 @@ use polars_io::parquet::read::ParallelStrategy;
 use polars_io::{HiveOptions, RowIndex};
 
 use crate::prelude::*;
+use crate::scan::file_list_reader::{expand_paths_unified, finish_unified};
 
 #[derive(Clone)]
 pub struct ScanArgsParquet {
@@ -21,6 +22,10 @@ pub struct ScanArgsParquet {
     /// Expand path given via globbing rules.
     pub glob: bool,
     pub include_file_paths: Option<PlSmallStr>,
+    /// Allow the files to have differing schemas. Columns missing from a file are
+    /// filled with nulls and differing dtypes are cast to their supertype. This
+    /// requires hive partitioning to not be enabled.
+    pub unify_schemas: bool,
 }
 
 impl Default for ScanArgsParquet {
@@ -37,6 +42,7 @@ impl Default for ScanArgsParquet {
             cache: true,
             glob: true,
             include_file_paths: None,
+            unify_schemas: false,
         }
     }
 }
@@ -59,6 +65,24 @@ impl LazyParquetReader {
 impl LazyFileListReader for LazyParquetReader {
     /// Get the final [LazyFrame].
     fn finish(self) -> PolarsResult<LazyFrame> {
+        if self.args.unify_schemas {
+            if let ScanSources::Paths(paths) = &self.sources {
+                let paths = expand_paths_unified(
+                    paths,
+                    self.args.glob,
+                    self.cloud_options(),
+                    &self.args.hive_options,
+                )?;
+                if paths.len() > 1 {
+                    let mut reader = self;
+                    reader.args.glob = false;
+                    reader.args.unify_schemas = false;
+                    reader.args.hive_options.enabled = Some(false);
+                    return finish_unified(reader, &paths, |r| r.finish());
+                }
+            }
+        }
+
         let row_index = self.args.row_index;
 
         let mut lf: LazyFrame = DslBuilder::scan_parquet(
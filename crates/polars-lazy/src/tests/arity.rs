@@ -36,6 +36,21 @@ fn test_pearson_corr() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+#[cfg(feature = "cov")]
+fn test_corr_cov_length_mismatch() -> PolarsResult<()> {
+    let df = df! {
+        "a" => [1.0, 2.0, 3.0],
+    }?;
+    let b = lit(Series::new("b".into(), [1.0, 2.0]));
+
+    for expr in [pearson_corr(col("a"), b.clone(), 1), cov(col("a"), b, 1)] {
+        let out = df.clone().lazy().select([expr]).collect();
+        assert!(matches!(out, Err(PolarsError::ShapeMismatch(_))));
+    }
+    Ok(())
+}
+
 // TODO! fix this we must get a token that prevents resetting the string cache until the plan has
 // finished running. We cannot store a mutexguard in the executionstate because they don't implement
 // send.
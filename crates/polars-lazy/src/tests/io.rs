@@ -1,4 +1,4 @@
-use polars_io::RowIndex;
+use polars_io::{HiveOptions, RowIndex};
 #[cfg(feature = "is_between")]
 use polars_ops::prelude::ClosedInterval;
 
@@ -420,6 +420,7 @@ fn test_ipc_globbing() -> PolarsResult<()> {
             cloud_options: None,
             hive_options: Default::default(),
             include_file_paths: None,
+            unify_schemas: false,
         },
     )?
     .collect()?;
@@ -431,6 +432,128 @@ fn test_ipc_globbing() -> PolarsResult<()> {
     Ok(())
 }
 
+fn write_differing_schemas(dir: &std::path::Path, ext: &str) -> PolarsResult<()> {
+    std::fs::create_dir_all(dir)?;
+    let mut df1 = df![
+        "a" => [1i32, 2],
+        "b" => ["x", "y"]
+    ]?;
+    let mut df2 = df![
+        "a" => [3i64],
+        "c" => [true]
+    ]?;
+    for (i, df) in [&mut df1, &mut df2].into_iter().enumerate() {
+        let f = std::fs::File::create(dir.join(format!("{i}.{ext}")))?;
+        match ext {
+            #[cfg(feature = "parquet")]
+            "parquet" => {
+                ParquetWriter::new(f).finish(df)?;
+            },
+            #[cfg(feature = "ipc")]
+            "ipc" => IpcWriter::new(f).finish(df)?,
+            _ => unreachable!(),
+        }
+    }
+    Ok(())
+}
+
+fn check_unified_schemas(df: &DataFrame) -> PolarsResult<()> {
+    let expected = df![
+        "a" => [1i64, 2, 3],
+        "b" => [Some("x"), Some("y"), None],
+        "c" => [None, None, Some(true)]
+    ]?;
+    assert!(df.equals_missing(&expected));
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "parquet", not(target_os = "windows")))]
+fn test_parquet_unify_schemas() -> PolarsResult<()> {
+    let dir = std::env::temp_dir().join("polars_lazy_unify_schemas_parquet");
+    write_differing_schemas(&dir, "parquet")?;
+    let glob = dir.join("*.parquet");
+    let glob = glob.to_str().unwrap();
+
+    assert!(LazyFrame::scan_parquet(glob, Default::default())?
+        .collect()
+        .is_err());
+
+    let args = ScanArgsParquet {
+        unify_schemas: true,
+        ..Default::default()
+    };
+    let df = LazyFrame::scan_parquet(glob, args.clone())?.collect()?;
+    check_unified_schemas(&df)?;
+
+    let args = ScanArgsParquet {
+        n_rows: Some(2),
+        row_index: Some(RowIndex {
+            name: "idx".into(),
+            offset: 0,
+        }),
+        ..args
+    };
+    let df = LazyFrame::scan_parquet(glob, args)?.collect()?;
+    assert_eq!(df.get_column_names(), &["idx", "a", "b", "c"]);
+    assert_eq!(df.height(), 2);
+
+    std::fs::remove_dir_all(&dir)?;
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "ipc", not(target_os = "windows")))]
+fn test_ipc_unify_schemas() -> PolarsResult<()> {
+    let dir = std::env::temp_dir().join("polars_lazy_unify_schemas_ipc");
+    write_differing_schemas(&dir, "ipc")?;
+    let glob = dir.join("*.ipc");
+
+    let err = LazyFrame::scan_ipc(glob.to_str().unwrap(), Default::default())?
+        .collect()
+        .unwrap_err();
+    assert!(matches!(err, PolarsError::SchemaMismatch(_)), "{err}");
+
+    let args = ScanArgsIpc {
+        unify_schemas: true,
+        ..Default::default()
+    };
+    let df = LazyFrame::scan_ipc(glob.to_str().unwrap(), args)?.collect()?;
+    check_unified_schemas(&df)?;
+
+    std::fs::remove_dir_all(&dir)?;
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "parquet", not(target_os = "windows")))]
+fn test_parquet_unify_schemas_hive() -> PolarsResult<()> {
+    let dir = std::env::temp_dir().join("polars_lazy_unify_schemas_hive");
+    write_differing_schemas(&dir.join("part=1"), "parquet")?;
+    let glob = dir.join("**/*.parquet");
+    let glob = glob.to_str().unwrap();
+
+    let args = ScanArgsParquet {
+        unify_schemas: true,
+        ..Default::default()
+    };
+    let out = LazyFrame::scan_parquet(glob, args.clone());
+    assert!(matches!(out, Err(PolarsError::InvalidOperation(_))));
+
+    let args = ScanArgsParquet {
+        hive_options: HiveOptions {
+            enabled: Some(false),
+            ..Default::default()
+        },
+        ..args
+    };
+    let df = LazyFrame::scan_parquet(glob, args)?.collect()?;
+    check_unified_schemas(&df)?;
+
+    std::fs::remove_dir_all(&dir)?;
+    Ok(())
+}
+
 fn slice_at_union(lp_arena: &Arena<IR>, lp: Node) -> bool {
     (&lp_arena).iter(lp).all(|(_, lp)| {
         if let IR::Union { options, .. } = lp {
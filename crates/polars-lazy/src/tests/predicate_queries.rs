@@ -330,3 +330,38 @@ fn test_push_join_col_predicates_to_both_sides_semi_12565() -> PolarsResult<()>
     assert_eq!(out, expected);
     Ok(())
 }
+
+#[test]
+#[cfg(feature = "asof_join")]
+fn test_asof_join_predicate_pushdown() -> PolarsResult<()> {
+    let left = df! {
+        "t" => [1i64, 5, 10],
+        "a" => [1, 2, 3],
+    }?;
+    let right = df! {
+        "t" => [1i64, 4, 9],
+        "flag" => [true, false, true],
+    }?;
+    let asof = |left: DataFrame, right: DataFrame| {
+        left.lazy()
+            .join_builder()
+            .with(right.lazy())
+            .left_on([col("t")])
+            .right_on([col("t")])
+            .how(JoinType::AsOf(polars_ops::prelude::AsOfOptions::default()))
+            .finish()
+    };
+
+    // predicates on the left table can be pushed down
+    let q = asof(left.clone(), right.clone()).filter(col("a").gt(lit(1)));
+    assert!(predicate_at_scan(q.clone()));
+    assert_eq!(q.collect()?.height(), 2);
+
+    // predicates on the right table would change the matched rows
+    let q = asof(left, right).filter(col("flag"));
+    assert!(!predicate_at_scan(q.clone()));
+    let out = q.clone().collect()?;
+    assert!(out.equals(&q.with_predicate_pushdown(false).collect()?));
+    assert_eq!(Vec::from(out.column("t")?.i64()?), &[Some(1), Some(10)]);
+    Ok(())
+}
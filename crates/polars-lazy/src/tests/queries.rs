@@ -14,6 +14,82 @@ fn test_lazy_with_column() {
     assert!(df.column("foo").is_ok());
 }
 
+#[test]
+fn test_lazy_with_columns_same_input() -> PolarsResult<()> {
+    let df = df![
+        "a" => [1, 2, 3],
+        "b" => [4, 5, 6],
+    ]?;
+
+    // Every expression sees the input columns, not the ones replaced in the same call.
+    let out = df
+        .clone()
+        .lazy()
+        .with_columns([
+            (col("a") * lit(10)).alias("a"),
+            (col("a") + col("b")).alias("c"),
+        ])
+        .collect()?;
+    let expected = df![
+        "a" => [10, 20, 30],
+        "b" => [4, 5, 6],
+        "c" => [5, 7, 9],
+    ]?;
+    assert!(out.equals(&expected));
+
+    let out = df
+        .lazy()
+        .with_columns([lit(1).alias("x"), lit(2).alias("x")])
+        .collect();
+    assert!(out.unwrap_err().to_string().contains("is duplicate"));
+    Ok(())
+}
+
+#[test]
+fn test_lazy_with_context() -> PolarsResult<()> {
+    let df = df![
+        "a" => [1, 2, 3],
+        "b" => ["x", "y", "z"],
+    ]?;
+    let other = df![
+        "c" => [10, 20],
+        "d" => [1.5, 2.5],
+    ]?;
+
+    let out = df
+        .clone()
+        .lazy()
+        .with_context([other.clone().lazy()])
+        .select([col("a") + col("c").max(), col("b")])
+        .collect()?;
+    let expected = df![
+        "a" => [21, 22, 23],
+        "b" => ["x", "y", "z"],
+    ]?;
+    assert!(out.equals(&expected));
+
+    // Context columns are only visible to expressions.
+    let out = df
+        .clone()
+        .lazy()
+        .with_context([other.clone().lazy()])
+        .with_columns([col("d").sum().alias("d_sum")])
+        .collect()?;
+    assert_eq!(out.get_column_names(), &["a", "b", "d_sum"]);
+    assert_eq!(
+        Vec::from(out.column("d_sum")?.f64()?),
+        &[Some(4.0), Some(4.0), Some(4.0)]
+    );
+
+    let out = df
+        .lazy()
+        .with_context([other.lazy()])
+        .select([col("missing")])
+        .collect();
+    assert!(out.is_err());
+    Ok(())
+}
+
 #[test]
 fn test_lazy_exec() {
     let df = get_df();
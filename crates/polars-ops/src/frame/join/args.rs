@@ -28,6 +28,11 @@ pub struct JoinArgs {
     pub slice: Option<(i64, usize)>,
     pub join_nulls: bool,
     pub coalesce: JoinCoalesce,
+    /// Cast numeric keys of different dtypes to a lossless common supertype, and categorical
+    /// keys joined with string keys to `String`, instead of raising a dtype mismatch.
+    pub coerce_keys: bool,
+    /// Override the heuristic that selects the join algorithm.
+    pub algorithm: JoinAlgorithm,
 }
 
 impl JoinArgs {
@@ -67,6 +72,26 @@ impl JoinCoalesce {
     }
 }
 
+/// The algorithm used to compute the indices of a single key inner, left or right join.
+///
+/// Other joins, and joins on multiple keys, always use a hash join.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum JoinAlgorithm {
+    /// Use a sorted merge join if the keys are sorted, a broadcast join if one side has at
+    /// most `POLARS_BROADCAST_JOIN_THRESHOLD` rows and a hash join otherwise.
+    #[default]
+    Auto,
+    Hash,
+    /// Merge the sorted keys, sorting them first where needed.
+    /// The keys must be numeric and may not contain nulls.
+    SortMerge,
+    /// Compare every row with every value of the build side instead of building a hash table.
+    /// The build side is the shorter side in an inner join, the right side in a left join and
+    /// the left side in a right join.
+    Broadcast,
+}
+
 impl Default for JoinArgs {
     fn default() -> Self {
         Self {
@@ -76,6 +101,8 @@ impl Default for JoinArgs {
             slice: None,
             join_nulls: false,
             coalesce: Default::default(),
+            coerce_keys: true,
+            algorithm: Default::default(),
         }
     }
 }
@@ -89,6 +116,8 @@ impl JoinArgs {
             slice: None,
             join_nulls: false,
             coalesce: Default::default(),
+            coerce_keys: true,
+            algorithm: Default::default(),
         }
     }
 
@@ -97,6 +126,16 @@ impl JoinArgs {
         self
     }
 
+    pub fn with_coerce_keys(mut self, coerce_keys: bool) -> Self {
+        self.coerce_keys = coerce_keys;
+        self
+    }
+
+    pub fn with_algorithm(mut self, algorithm: JoinAlgorithm) -> Self {
+        self.algorithm = algorithm;
+        self
+    }
+
     pub fn with_suffix(mut self, suffix: Option<PlSmallStr>) -> Self {
         self.suffix = suffix;
         self
@@ -67,7 +67,14 @@ pub fn materialize_left_join_from_series(
         s_right = s_right.rechunk();
     }
 
-    let ids = sort_or_hash_left(&s_left, &s_right, verbose, args.validation, args.join_nulls)?;
+    let ids = sort_or_hash_left(
+        &s_left,
+        &s_right,
+        verbose,
+        args.validation,
+        args.join_nulls,
+        args.algorithm,
+    )?;
     let right = if let Some(drop_names) = drop_names {
         right.drop_many(drop_names)
     } else {
@@ -0,0 +1,130 @@
+use arrow::bitmap::Bitmap;
+use polars_core::export::once_cell::sync::Lazy;
+use polars_core::utils::_split_offsets;
+use polars_core::utils::flatten::flatten_par;
+
+use super::*;
+
+/// The maximum number of rows of the build side for which a broadcast join is used instead
+/// of a hash join.
+static BROADCAST_JOIN_THRESHOLD: Lazy<usize> = Lazy::new(|| {
+    const DEFAULT: usize = 8;
+    match std::env::var("POLARS_BROADCAST_JOIN_THRESHOLD") {
+        Ok(s) => s.parse::<usize>().unwrap_or_else(|_| {
+            polars_warn!(
+                "POLARS_BROADCAST_JOIN_THRESHOLD must be a non-negative integer, got {:?}; using {}",
+                s,
+                DEFAULT
+            );
+            DEFAULT
+        }),
+        Err(_) => DEFAULT,
+    }
+});
+
+/// Whether the heuristic should broadcast `build` instead of building a hash table from it.
+pub(super) fn use_broadcast_join(build: &Series) -> bool {
+    let dtype = build.dtype().to_physical();
+    build.len() <= *BROADCAST_JOIN_THRESHOLD
+        && (dtype.is_numeric() || matches!(dtype, DataType::String | DataType::Binary))
+}
+
+/// For every row of `build`, the rows of `probe` that are equal to it.
+fn broadcast_masks(probe: &Series, build: &Series, join_nulls: bool) -> PolarsResult<Vec<Bitmap>> {
+    let probe = probe.to_physical_repr().rechunk();
+    let build = build.to_physical_repr();
+
+    (0..build.len())
+        .map(|i| {
+            let value = build.slice(i as i64, 1);
+            let mask = if join_nulls {
+                probe.equal_missing(&value)?
+            } else {
+                probe.equal(&value)?
+            };
+            let mask = mask.rechunk();
+            let arr = mask.downcast_iter().next().unwrap();
+            Ok(match arr.validity() {
+                Some(validity) => arr.values() & validity,
+                None => arr.values().clone(),
+            })
+        })
+        .collect()
+}
+
+/// Probe every row of `probe` against the masks in parallel. `f` is called with the probe
+/// index and an iterator over the matching build indices and collects the join indices.
+fn probe_masks<L, R, F>(len: usize, masks: &[Bitmap], f: F) -> (Vec<L>, Vec<R>)
+where
+    L: Send + Sync + Copy,
+    R: Send + Sync + Copy,
+    F: Fn(IdxSize, &mut dyn Iterator<Item = IdxSize>, &mut Vec<L>, &mut Vec<R>) + Sync,
+{
+    let offsets = _split_offsets(len, POOL.current_num_threads());
+    let ids = POOL.install(|| {
+        offsets
+            .into_par_iter()
+            .map(|(offset, len)| {
+                let mut left = Vec::with_capacity(len);
+                let mut right = Vec::with_capacity(len);
+                for i in offset..offset + len {
+                    let mut matches = masks
+                        .iter()
+                        .enumerate()
+                        .filter(|(_, mask)| mask.get_bit(i))
+                        .map(|(j, _)| j as IdxSize);
+                    f(i as IdxSize, &mut matches, &mut left, &mut right);
+                }
+                (left, right)
+            })
+            .collect::<Vec<_>>()
+    });
+
+    let lefts = ids.iter().map(|t| &t.0).collect::<Vec<_>>();
+    let rights = ids.iter().map(|t| &t.1).collect::<Vec<_>>();
+    (flatten_par(&lefts), flatten_par(&rights))
+}
+
+/// Inner join that broadcasts the shorter key. Also returns whether the left indices are
+/// sorted.
+pub(super) fn broadcast_join_inner(
+    s_left: &Series,
+    s_right: &Series,
+    join_nulls: bool,
+) -> PolarsResult<(InnerJoinIds, bool)> {
+    let (probe, build, swapped) = det_hash_prone_order!(s_left, s_right);
+    let masks = broadcast_masks(probe, build, join_nulls)?;
+    let (probe_idx, build_idx) = probe_masks(probe.len(), &masks, |i, matches, left, right| {
+        for j in matches {
+            left.push(i);
+            right.push(j);
+        }
+    });
+
+    if swapped {
+        Ok(((build_idx, probe_idx), false))
+    } else {
+        Ok(((probe_idx, build_idx), true))
+    }
+}
+
+/// Left join that broadcasts the right key.
+pub(super) fn broadcast_join_left(
+    s_left: &Series,
+    s_right: &Series,
+    join_nulls: bool,
+) -> PolarsResult<LeftJoinIds> {
+    let masks = broadcast_masks(s_left, s_right, join_nulls)?;
+    let (left, right) = probe_masks(s_left.len(), &masks, |i, matches, left, right| {
+        let n = left.len();
+        for j in matches {
+            left.push(i);
+            right.push(NullableIdxSize::from(j));
+        }
+        if left.len() == n {
+            left.push(i);
+            right.push(NullableIdxSize::null());
+        }
+    });
+    Ok(to_left_join_ids(left, right))
+}
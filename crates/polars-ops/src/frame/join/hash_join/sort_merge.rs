@@ -1,6 +1,8 @@
 #[cfg(feature = "performant")]
 use arrow::legacy::kernels::sorted_join;
 #[cfg(feature = "performant")]
+use polars_core::export::once_cell::sync::Lazy;
+#[cfg(feature = "performant")]
 use polars_core::utils::_split_offsets;
 #[cfg(feature = "performant")]
 use polars_core::utils::flatten::flatten_par;
@@ -152,8 +154,10 @@ pub(super) fn par_sorted_merge_inner_no_nulls(
     }
 }
 
-#[cfg(feature = "performant")]
-fn to_left_join_ids(left_idx: Vec<IdxSize>, right_idx: Vec<NullableIdxSize>) -> LeftJoinIds {
+pub(super) fn to_left_join_ids(
+    left_idx: Vec<IdxSize>,
+    right_idx: Vec<NullableIdxSize>,
+) -> LeftJoinIds {
     #[cfg(feature = "chunked_ids")]
     {
         (Either::Left(left_idx), Either::Left(right_idx))
@@ -171,15 +175,155 @@ fn create_reverse_map_from_arg_sort(mut arg_sort: IdxCa) -> Vec<IdxSize> {
     primitive_to_vec::<IdxSize>(arr).unwrap()
 }
 
+/// The maximum size ratio of the unsorted key over the sorted key for which the unsorted
+/// key is sorted so that a sorted merge join can be used instead of a hash join.
+#[cfg(feature = "performant")]
+static SIZE_FACTOR_ACCEPTABLE: Lazy<f32> = Lazy::new(|| {
+    const DEFAULT: f32 = 1.0;
+    match std::env::var("POLARS_JOIN_SORT_FACTOR") {
+        Ok(s) => s.parse::<f32>().unwrap_or_else(|_| {
+            polars_warn!(
+                "POLARS_JOIN_SORT_FACTOR must be a floating point number, got {:?}; using {}",
+                s,
+                DEFAULT
+            );
+            DEFAULT
+        }),
+        Err(_) => DEFAULT,
+    }
+});
+
+/// Whether `POLARS_NO_SORT_MERGE_JOIN` forces a hash join, even if the keys are sorted.
+#[cfg(feature = "performant")]
+static NO_SORT_MERGE_JOIN: Lazy<bool> =
+    Lazy::new(|| std::env::var("POLARS_NO_SORT_MERGE_JOIN").is_ok());
+
+/// Join validation is only implemented by the hash join.
+fn check_validation(validate: JoinValidation, algorithm: JoinAlgorithm) -> PolarsResult<()> {
+    polars_ensure!(
+        !validate.needs_checks() || matches!(algorithm, JoinAlgorithm::Auto | JoinAlgorithm::Hash),
+        InvalidOperation: "join validation is not supported by the {:?} join algorithm", algorithm
+    );
+    Ok(())
+}
+
+/// Sort `s` if it isn't sorted ascending yet, and return the map from the sorted to the
+/// original positions if it was sorted.
+#[cfg(feature = "performant")]
+fn sort_join_key(s: &Series) -> (Series, Option<Vec<IdxSize>>) {
+    if s.is_sorted_flag() == IsSorted::Ascending {
+        return (s.clone(), None);
+    }
+    let sort_idx = s.arg_sort(SortOptions {
+        descending: false,
+        nulls_last: false,
+        multithreaded: true,
+        maintain_order: false,
+    });
+    let sorted = unsafe { s.take_unchecked(&sort_idx) };
+    (sorted, Some(create_reverse_map_from_arg_sort(sort_idx)))
+}
+
+#[cfg(feature = "performant")]
+fn check_sort_merge_keys(s_left: &Series, s_right: &Series) -> PolarsResult<()> {
+    polars_ensure!(
+        s_left.dtype().to_physical().is_numeric()
+            && s_left.null_count() == 0
+            && s_right.null_count() == 0,
+        InvalidOperation: "sort-merge join requires numeric keys without nulls, got {}", s_left.dtype()
+    );
+    Ok(())
+}
+
+/// Inner join with [`JoinAlgorithm::SortMerge`].
+#[cfg(feature = "performant")]
+fn sort_merge_join_inner(s_left: &Series, s_right: &Series) -> PolarsResult<(InnerJoinIds, bool)> {
+    check_sort_merge_keys(s_left, s_right)?;
+    let (s_left, left_map) = sort_join_key(s_left);
+    let (s_right, right_map) = sort_join_key(s_right);
+    let (mut left, mut right) = par_sorted_merge_inner_no_nulls(&s_left, &s_right);
+
+    POOL.install(|| {
+        for (ids, map) in [(&mut left, &left_map), (&mut right, &right_map)] {
+            if let Some(map) = map {
+                ids.par_iter_mut().for_each(|idx| {
+                    *idx = unsafe { *map.get_unchecked(*idx as usize) };
+                });
+            }
+        }
+    });
+    Ok(((left, right), left_map.is_none()))
+}
+
+/// Left join with [`JoinAlgorithm::SortMerge`].
+#[cfg(feature = "performant")]
+fn sort_merge_join_left(s_left: &Series, s_right: &Series) -> PolarsResult<LeftJoinIds> {
+    check_sort_merge_keys(s_left, s_right)?;
+    let (s_left, left_map) = sort_join_key(s_left);
+    let (s_right, right_map) = sort_join_key(s_right);
+    let (mut left, mut right) = par_sorted_merge_left(&s_left, &s_right);
+
+    POOL.install(|| {
+        if let Some(map) = &right_map {
+            right.par_iter_mut().for_each(|opt_idx| {
+                if !opt_idx.is_null_idx() {
+                    *opt_idx = unsafe { *map.get_unchecked(opt_idx.idx() as usize) }.into();
+                }
+            });
+        }
+        if let Some(map) = &left_map {
+            left.par_iter_mut().for_each(|idx| {
+                *idx = unsafe { *map.get_unchecked(*idx as usize) };
+            });
+            // Restore the order of the left table. The sort is stable, so the matches of a
+            // row stay in order.
+            let mut order = (0..left.len() as IdxSize).collect::<Vec<_>>();
+            order.par_sort_by_key(|&i| left[i as usize]);
+            left = order.par_iter().map(|&i| left[i as usize]).collect();
+            right = order.par_iter().map(|&i| right[i as usize]).collect();
+        }
+    });
+    Ok(to_left_join_ids(left, right))
+}
+
 #[cfg(not(feature = "performant"))]
 pub(crate) fn _sort_or_hash_inner(
     s_left: &Series,
     s_right: &Series,
-    _verbose: bool,
+    verbose: bool,
+    validate: JoinValidation,
+    join_nulls: bool,
+    algorithm: JoinAlgorithm,
+) -> PolarsResult<(InnerJoinIds, bool)> {
+    check_validation(validate, algorithm)?;
+    match algorithm {
+        JoinAlgorithm::SortMerge => {
+            polars_bail!(InvalidOperation: "sort-merge join requires the 'performant' feature")
+        },
+        JoinAlgorithm::Broadcast => broadcast_join_inner(s_left, s_right, join_nulls),
+        JoinAlgorithm::Auto if !validate.needs_checks() => {
+            broadcast_or_hash_inner(s_left, s_right, verbose, validate, join_nulls)
+        },
+        _ => s_left.hash_join_inner(s_right, validate, join_nulls),
+    }
+}
+
+fn broadcast_or_hash_inner(
+    s_left: &Series,
+    s_right: &Series,
+    verbose: bool,
     validate: JoinValidation,
     join_nulls: bool,
 ) -> PolarsResult<(InnerJoinIds, bool)> {
-    s_left.hash_join_inner(s_right, validate, join_nulls)
+    let (_, build, _) = det_hash_prone_order!(s_left, s_right);
+    if use_broadcast_join(build) {
+        if verbose {
+            eprintln!("inner join: one key is tiny: use broadcast join");
+        }
+        broadcast_join_inner(s_left, s_right, join_nulls)
+    } else {
+        s_left.hash_join_inner(s_right, validate, join_nulls)
+    }
 }
 
 #[cfg(feature = "performant")]
@@ -189,21 +333,31 @@ pub(crate) fn _sort_or_hash_inner(
     verbose: bool,
     validate: JoinValidation,
     join_nulls: bool,
+    algorithm: JoinAlgorithm,
 ) -> PolarsResult<(InnerJoinIds, bool)> {
+    check_validation(validate, algorithm)?;
+    match algorithm {
+        JoinAlgorithm::Auto => {},
+        JoinAlgorithm::Hash => return s_left.hash_join_inner(s_right, validate, join_nulls),
+        JoinAlgorithm::SortMerge => return sort_merge_join_inner(s_left, s_right),
+        JoinAlgorithm::Broadcast => return broadcast_join_inner(s_left, s_right, join_nulls),
+    }
+
     // We check if keys are sorted.
     // - If they are we can do a sorted merge join
     // If one of the keys is not, it can still be faster to sort that key and use
     // the `arg_sort` indices to revert the sort once the join keys are determined.
     let size_factor_rhs = s_right.len() as f32 / s_left.len() as f32;
     let size_factor_lhs = s_left.len() as f32 / s_right.len() as f32;
-    let size_factor_acceptable = std::env::var("POLARS_JOIN_SORT_FACTOR")
-        .map(|s| s.parse::<f32>().unwrap())
-        .unwrap_or(1.0);
+    let size_factor_acceptable = *SIZE_FACTOR_ACCEPTABLE;
     let is_numeric = s_left.dtype().to_physical().is_numeric();
 
     if validate.needs_checks() {
         return s_left.hash_join_inner(s_right, validate, join_nulls);
     }
+    if *NO_SORT_MERGE_JOIN {
+        return broadcast_or_hash_inner(s_left, s_right, verbose, validate, join_nulls);
+    }
 
     let no_nulls = s_left.null_count() == 0 && s_right.null_count() == 0;
     match (s_left.is_sorted_flag(), s_right.is_sorted_flag(), no_nulls) {
@@ -217,7 +371,7 @@ pub(crate) fn _sort_or_hash_inner(
             if is_numeric && size_factor_rhs < size_factor_acceptable =>
         {
             if verbose {
-                eprintln!("right key will be descending sorted in inner join operation.")
+                eprintln!("right key will be sorted in inner join operation.")
             }
 
             let sort_idx = s_right.arg_sort(SortOptions {
@@ -244,7 +398,7 @@ pub(crate) fn _sort_or_hash_inner(
             if is_numeric && size_factor_lhs < size_factor_acceptable =>
         {
             if verbose {
-                eprintln!("left key will be descending sorted in inner join operation.")
+                eprintln!("left key will be sorted in inner join operation.")
             }
 
             let sort_idx = s_left.arg_sort(SortOptions {
@@ -265,10 +419,10 @@ pub(crate) fn _sort_or_hash_inner(
                 });
             });
 
-            // set sorted to `false` as we descending sorted the left key.
+            // set sorted to `false` as we sorted the left key.
             Ok(((left, right), false))
         },
-        _ => s_left.hash_join_inner(s_right, validate, join_nulls),
+        _ => broadcast_or_hash_inner(s_left, s_right, verbose, validate, join_nulls),
     }
 }
 
@@ -276,11 +430,39 @@ pub(crate) fn _sort_or_hash_inner(
 pub(crate) fn sort_or_hash_left(
     s_left: &Series,
     s_right: &Series,
-    _verbose: bool,
+    verbose: bool,
+    validate: JoinValidation,
+    join_nulls: bool,
+    algorithm: JoinAlgorithm,
+) -> PolarsResult<LeftJoinIds> {
+    check_validation(validate, algorithm)?;
+    match algorithm {
+        JoinAlgorithm::SortMerge => {
+            polars_bail!(InvalidOperation: "sort-merge join requires the 'performant' feature")
+        },
+        JoinAlgorithm::Broadcast => broadcast_join_left(s_left, s_right, join_nulls),
+        JoinAlgorithm::Auto if !validate.needs_checks() => {
+            broadcast_or_hash_left(s_left, s_right, verbose, validate, join_nulls)
+        },
+        _ => s_left.hash_join_left(s_right, validate, join_nulls),
+    }
+}
+
+fn broadcast_or_hash_left(
+    s_left: &Series,
+    s_right: &Series,
+    verbose: bool,
     validate: JoinValidation,
     join_nulls: bool,
 ) -> PolarsResult<LeftJoinIds> {
-    s_left.hash_join_left(s_right, validate, join_nulls)
+    if use_broadcast_join(s_right) {
+        if verbose {
+            eprintln!("left join: right key is tiny: use broadcast join");
+        }
+        broadcast_join_left(s_left, s_right, join_nulls)
+    } else {
+        s_left.hash_join_left(s_right, validate, join_nulls)
+    }
 }
 
 #[cfg(feature = "performant")]
@@ -290,15 +472,25 @@ pub(crate) fn sort_or_hash_left(
     verbose: bool,
     validate: JoinValidation,
     join_nulls: bool,
+    algorithm: JoinAlgorithm,
 ) -> PolarsResult<LeftJoinIds> {
+    check_validation(validate, algorithm)?;
+    match algorithm {
+        JoinAlgorithm::Auto => {},
+        JoinAlgorithm::Hash => return s_left.hash_join_left(s_right, validate, join_nulls),
+        JoinAlgorithm::SortMerge => return sort_merge_join_left(s_left, s_right),
+        JoinAlgorithm::Broadcast => return broadcast_join_left(s_left, s_right, join_nulls),
+    }
+
     if validate.needs_checks() {
         return s_left.hash_join_left(s_right, validate, join_nulls);
     }
+    if *NO_SORT_MERGE_JOIN {
+        return broadcast_or_hash_left(s_left, s_right, verbose, validate, join_nulls);
+    }
 
     let size_factor_rhs = s_right.len() as f32 / s_left.len() as f32;
-    let size_factor_acceptable = std::env::var("POLARS_JOIN_SORT_FACTOR")
-        .map(|s| s.parse::<f32>().unwrap())
-        .unwrap_or(1.0);
+    let size_factor_acceptable = *SIZE_FACTOR_ACCEPTABLE;
     let is_numeric = s_left.dtype().to_physical().is_numeric();
 
     let no_nulls = s_left.null_count() == 0 && s_right.null_count() == 0;
@@ -315,7 +507,7 @@ pub(crate) fn sort_or_hash_left(
             if is_numeric && size_factor_rhs < size_factor_acceptable =>
         {
             if verbose {
-                eprintln!("right key will be reverse sorted in left join operation.")
+                eprintln!("right key will be sorted in left join operation.")
             }
 
             let sort_idx = s_right.arg_sort(SortOptions {
@@ -343,6 +535,6 @@ pub(crate) fn sort_or_hash_left(
             Ok(to_left_join_ids(left, right))
         },
         // don't reverse sort a left join key yet. Have to figure out how to set sorted flag
-        _ => s_left.hash_join_left(s_right, validate, join_nulls),
+        _ => broadcast_or_hash_left(s_left, s_right, verbose, validate, join_nulls),
     }
 }
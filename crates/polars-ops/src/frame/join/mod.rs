@@ -483,8 +483,14 @@ trait DataFrameJoinOpsPrivate: IntoDf {
         let left_df = self.to_df();
         #[cfg(feature = "dtype-categorical")]
         _check_categorical_src(s_left.dtype(), s_right.dtype())?;
-        let ((join_tuples_left, join_tuples_right), sorted) =
-            _sort_or_hash_inner(s_left, s_right, verbose, args.validation, args.join_nulls)?;
+        let ((join_tuples_left, join_tuples_right), sorted) = _sort_or_hash_inner(
+            s_left,
+            s_right,
+            verbose,
+            args.validation,
+            args.join_nulls,
+            args.algorithm,
+        )?;
 
         let mut join_tuples_left = &*join_tuples_left;
         let mut join_tuples_right = &*join_tuples_right;
@@ -539,5 +545,12 @@ pub fn private_left_join_multiple_keys(
 ) -> PolarsResult<LeftJoinIds> {
     let a = prepare_keys_multiple(a.get_columns(), join_nulls)?.into_series();
     let b = prepare_keys_multiple(b.get_columns(), join_nulls)?.into_series();
-    sort_or_hash_left(&a, &b, false, JoinValidation::ManyToMany, join_nulls)
+    sort_or_hash_left(
+        &a,
+        &b,
+        false,
+        JoinValidation::ManyToMany,
+        join_nulls,
+        JoinAlgorithm::Auto,
+    )
 }
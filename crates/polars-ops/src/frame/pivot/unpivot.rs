@@ -138,7 +138,14 @@ pub trait UnpivotDF: IntoDf {
             .map(|v| schema.get(v).ok_or_else(|| polars_err!(col_not_found = v)));
         let mut st = iter.next().unwrap()?.clone();
         for dt in iter {
-            st = try_get_supertype(&st, dt?)?;
+            st = try_get_supertype(&st, dt?).map_err(|err| {
+                err.wrap_msg(|msg| {
+                    format!(
+                        "{msg}\n\n'unpivot' requires the columns in `on` to have a common \
+                        supertype; cast them to a shared dtype first"
+                    )
+                })
+            })?;
         }
 
         // The column name of the variable that is unpivoted
@@ -197,10 +204,38 @@ impl UnpivotDF for DataFrame {}
 #[cfg(test)]
 mod test {
     use polars_core::df;
+    use polars_core::prelude::{IntoSeries, NamedFrom, PolarsError};
     use polars_core::utils::Container;
 
     use super::*;
 
+    #[test]
+    fn test_unpivot_heterogeneous_dtypes() -> PolarsResult<()> {
+        let df = df!(
+            "id" => [1, 2],
+            "int" => [1i32, 2],
+            "float" => [0.5, 1.5]
+        )?;
+
+        // Numeric columns are cast to their supertype.
+        let unpivoted = df.unpivot(["int", "float"], ["id"])?;
+        assert_eq!(unpivoted.column("value")?.dtype(), &DataType::Float64);
+        assert_eq!(
+            Vec::from(unpivoted.column("value")?.f64()?),
+            &[Some(1.0), Some(2.0), Some(0.5), Some(1.5)]
+        );
+
+        let list = Series::new("list".into(), [1, 2])
+            .implode()?
+            .into_series()
+            .new_from_index(0, 2);
+        let df = df.hstack(&[list])?;
+        let err = df.unpivot(["int", "list"], ["id"]).unwrap_err();
+        assert!(matches!(err, PolarsError::SchemaMismatch(_)));
+        assert!(err.to_string().contains("common supertype"));
+        Ok(())
+    }
+
     #[test]
     fn test_unpivot() -> PolarsResult<()> {
         let df = df!("A" => &["a", "b", "a"],
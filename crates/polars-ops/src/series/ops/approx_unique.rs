@@ -76,3 +76,31 @@ fn dispatcher(s: &Series) -> PolarsResult<Series> {
 pub fn approx_n_unique(s: &Series) -> PolarsResult<Series> {
     dispatcher(s)
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn estimate(s: &Series) -> PolarsResult<IdxSize> {
+        Ok(approx_n_unique(s)?.idx()?.get(0).unwrap())
+    }
+
+    #[test]
+    fn test_approx_n_unique() -> PolarsResult<()> {
+        let s = Series::new("a".into(), [1, 2, 2, 3, 3, 3]);
+        assert_eq!(estimate(&s)?, 3);
+
+        // A null counts as one distinct value, like in `n_unique`.
+        let s = Series::new("a".into(), [Some("x"), None, Some("y"), None]);
+        assert_eq!(estimate(&s)?, s.n_unique()? as IdxSize);
+
+        let n = 100_000;
+        let s = Series::new("a".into(), (0..n).chain(0..n).collect::<Vec<i64>>());
+        let relative_error = (estimate(&s)? as f64 - n as f64).abs() / n as f64;
+        assert!(relative_error < 0.05, "{relative_error}");
+
+        let s = Series::new("a".into(), [1, 2]).implode()?.into_series();
+        assert!(approx_n_unique(&s).is_err());
+        Ok(())
+    }
+}
@@ -113,7 +113,7 @@ pub fn cut(
     }
 
     let cut_labels = if let Some(l) = labels {
-        polars_ensure!(l.len() == breaks.len() + 1, ShapeMismatch: "provide len(quantiles) + 1 labels");
+        polars_ensure!(l.len() == breaks.len() + 1, ShapeMismatch: "provide len(breaks) + 1 labels");
         l
     } else {
         compute_labels(&breaks, left_closed)?
@@ -144,12 +144,12 @@ pub fn qcut(
     let s2 = s.sort(SortOptions::default())?;
     let ca = s2.f64()?;
 
+    // There is at least one non-null value, so every quantile exists.
     let f = |&p| {
         ca.quantile(p, QuantileInterpolOptions::Linear)
-            .unwrap()
-            .unwrap()
+            .map(|q| q.unwrap())
     };
-    let mut qbreaks: Vec<_> = probs.iter().map(f).collect();
+    let mut qbreaks = probs.iter().map(f).collect::<PolarsResult<Vec<_>>>()?;
     qbreaks.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
 
     if !allow_duplicates {
@@ -166,7 +166,24 @@ pub fn qcut(
     map_cats(&s, &cut_labels, &qbreaks, left_closed, include_breaks)
 }
 
+#[cfg(test)]
 mod test {
+    use polars_core::prelude::*;
+
+    use super::{cut, qcut};
+
+    #[test]
+    fn test_cut_qcut_invalid_input() {
+        let s = Series::new("x".into(), &[1, 2, 3, 4, 5]);
+
+        let labels = Some(vec![PlSmallStr::from_static("a")]);
+        let err = cut(&s, vec![2.0, 4.0], labels, false, false).unwrap_err();
+        assert!(err.to_string().contains("len(breaks) + 1"));
+
+        // Quantiles outside of [0, 1] are an error rather than a panic.
+        assert!(qcut(&s, vec![0.5, 1.5], None, false, true, false).is_err());
+    }
+
     // This need metadata in fields
     #[ignore]
     #[test]
@@ -33,3 +33,25 @@ where
 
     Ok(ca.into_series())
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_int_range_steps() -> PolarsResult<()> {
+        let s = new_int_range::<Int64Type>(0, 10, 3, "a".into())?;
+        assert_eq!(Vec::from(s.i64()?), &[Some(0), Some(3), Some(6), Some(9)]);
+        assert_eq!(s.is_sorted_flag(), IsSorted::Ascending);
+
+        let s = new_int_range::<Int64Type>(10, 0, -3, "a".into())?;
+        assert_eq!(Vec::from(s.i64()?), &[Some(10), Some(7), Some(4), Some(1)]);
+        assert_eq!(s.is_sorted_flag(), IsSorted::Descending);
+
+        let s = new_int_range::<UInt32Type>(0, 5, -1, "a".into())?;
+        assert!(s.is_empty());
+
+        assert!(new_int_range::<Int32Type>(0, 5, 0, "a".into()).is_err());
+        Ok(())
+    }
+}
@@ -42,6 +42,7 @@ mod log;
 #[cfg(feature = "moment")]
 mod moment;
 mod negate;
+mod overflow;
 #[cfg(feature = "pct_change")]
 mod pct_change;
 #[cfg(feature = "rank")]
@@ -112,6 +113,7 @@ pub use log::*;
 #[cfg(feature = "moment")]
 pub use moment::*;
 pub use negate::*;
+pub use overflow::*;
 #[cfg(feature = "pct_change")]
 pub use pct_change::*;
 pub use polars_core::chunked_array::ops::search_sorted::SearchSortedSide;
@@ -0,0 +1,323 @@
+use num_traits::{
+    CheckedAdd, CheckedMul, CheckedSub, SaturatingAdd, SaturatingMul, SaturatingSub, Zero,
+};
+use polars_core::prelude::arity::{
+    broadcast_binary_elementwise_values, broadcast_try_binary_elementwise,
+};
+use polars_core::prelude::*;
+use polars_core::utils::try_get_supertype;
+use polars_core::with_match_physical_integer_polars_type;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// How integer overflow is handled by arithmetic and sums.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum OverflowPolicy {
+    /// Wrap around, which is what the regular integer kernels do.
+    #[default]
+    Wrap,
+    /// Clamp the result to the bounds of the integer type.
+    Saturate,
+    /// Raise an error.
+    Error,
+    /// Widen to the 64-bit integer type of the same signedness first and raise
+    /// an error if the result still overflows.
+    Upcast,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum OverflowOperator {
+    Add,
+    Sub,
+    Mul,
+}
+
+trait OverflowNative:
+    Copy + CheckedAdd + CheckedSub + CheckedMul + SaturatingAdd + SaturatingSub + SaturatingMul
+{
+}
+
+impl<T> OverflowNative for T where
+    T: Copy + CheckedAdd + CheckedSub + CheckedMul + SaturatingAdd + SaturatingSub + SaturatingMul
+{
+}
+
+impl OverflowOperator {
+    fn name(self) -> &'static str {
+        match self {
+            Self::Add => "add",
+            Self::Sub => "sub",
+            Self::Mul => "mul",
+        }
+    }
+
+    fn checked<T: OverflowNative>(self, a: T, b: T) -> Option<T> {
+        match self {
+            Self::Add => a.checked_add(&b),
+            Self::Sub => a.checked_sub(&b),
+            Self::Mul => a.checked_mul(&b),
+        }
+    }
+
+    fn saturating<T: OverflowNative>(self, a: T, b: T) -> T {
+        match self {
+            Self::Add => a.saturating_add(&b),
+            Self::Sub => a.saturating_sub(&b),
+            Self::Mul => a.saturating_mul(&b),
+        }
+    }
+}
+
+fn upcast_dtype(dtype: &DataType) -> DataType {
+    if dtype.is_signed_integer() {
+        DataType::Int64
+    } else {
+        DataType::UInt64
+    }
+}
+
+fn overflow_err(op: &str, dtype: &DataType) -> PolarsError {
+    polars_err!(ComputeError: "integer overflow in `{}` for dtype {}", op, dtype)
+}
+
+fn arithmetic_ca<T>(
+    lhs: &ChunkedArray<T>,
+    rhs: &ChunkedArray<T>,
+    op: OverflowOperator,
+    policy: OverflowPolicy,
+) -> PolarsResult<ChunkedArray<T>>
+where
+    T: PolarsIntegerType,
+    T::Native: OverflowNative,
+{
+    if policy == OverflowPolicy::Saturate {
+        return Ok(broadcast_binary_elementwise_values(lhs, rhs, |a, b| {
+            op.saturating(a, b)
+        }));
+    }
+    broadcast_try_binary_elementwise(lhs, rhs, |a, b| match (a, b) {
+        (Some(a), Some(b)) => op
+            .checked(a, b)
+            .map(Some)
+            .ok_or_else(|| overflow_err(op.name(), &T::get_dtype())),
+        _ => Ok(None),
+    })
+}
+
+/// Raise if `lhs` and `rhs` are integers but `supertype` can't hold all their values.
+///
+/// This is the case for a signed integer and `UInt64`. Their supertype is `Float64`, or `Int64`
+/// with `bigidx`, and the overflow policy can't be applied to either.
+pub fn ensure_integer_supertype(
+    lhs: &DataType,
+    rhs: &DataType,
+    supertype: &DataType,
+    op: OverflowOperator,
+) -> PolarsResult<()> {
+    let lossless = supertype.is_integer()
+        && (supertype == &DataType::UInt64
+            || (lhs != &DataType::UInt64 && rhs != &DataType::UInt64));
+    polars_ensure!(
+        !(lhs.is_integer() && rhs.is_integer()) || lossless,
+        InvalidOperation: "`{}` with an overflow policy requires an integer supertype that holds both {} and {}, got {}",
+        op.name(), lhs, rhs, supertype
+    );
+    Ok(())
+}
+
+/// Apply `op` to `lhs` and `rhs`, handling integer overflow according to `policy`.
+///
+/// Float inputs are not affected by the policy.
+pub fn arithmetic_with_overflow(
+    lhs: &Series,
+    rhs: &Series,
+    op: OverflowOperator,
+    policy: OverflowPolicy,
+) -> PolarsResult<Series> {
+    let dtype = try_get_supertype(lhs.dtype(), rhs.dtype())?;
+    polars_ensure!(
+        dtype.is_numeric(),
+        InvalidOperation: "`{}` with an overflow policy is only supported for numeric types, got {}",
+        op.name(), dtype
+    );
+    if policy != OverflowPolicy::Wrap {
+        ensure_integer_supertype(lhs.dtype(), rhs.dtype(), &dtype, op)?;
+    }
+    if policy == OverflowPolicy::Wrap || !dtype.is_integer() {
+        return match op {
+            OverflowOperator::Add => lhs + rhs,
+            OverflowOperator::Sub => lhs - rhs,
+            OverflowOperator::Mul => lhs * rhs,
+        };
+    }
+    polars_ensure!(
+        lhs.len() == rhs.len() || lhs.len() == 1 || rhs.len() == 1,
+        ShapeMismatch: "cannot `{}` series of length {} and {}", op.name(), lhs.len(), rhs.len()
+    );
+
+    let (dtype, policy) = match policy {
+        OverflowPolicy::Upcast => (upcast_dtype(&dtype), OverflowPolicy::Error),
+        policy => (dtype, policy),
+    };
+    let lhs = lhs.strict_cast(&dtype)?;
+    let rhs = rhs.strict_cast(&dtype)?;
+
+    with_match_physical_integer_polars_type!(&dtype, |$T| {
+        let lhs: &ChunkedArray<$T> = lhs.as_ref().as_ref().as_ref();
+        let rhs: &ChunkedArray<$T> = rhs.as_ref().as_ref().as_ref();
+
+        arithmetic_ca(lhs, rhs, op, policy).map(|ca| ca.into_series())
+    })
+}
+
+fn sum_ca<T>(ca: &ChunkedArray<T>, policy: OverflowPolicy) -> PolarsResult<ChunkedArray<T>>
+where
+    T: PolarsIntegerType,
+    T::Native: OverflowNative,
+{
+    let mut acc = T::Native::zero();
+    for v in ca.iter().flatten() {
+        acc = if policy == OverflowPolicy::Saturate {
+            acc.saturating_add(&v)
+        } else {
+            acc.checked_add(&v)
+                .ok_or_else(|| overflow_err("sum", &T::get_dtype()))?
+        };
+    }
+    Ok(ChunkedArray::from_slice(ca.name().clone(), &[acc]))
+}
+
+/// Get the sum of `s` as a new [`Series`] of length 1, handling integer overflow
+/// according to `policy`.
+///
+/// Like [`Series::sum_reduce`], `{Int8, UInt8, Int16, UInt16}` are summed as `Int64`.
+/// Saturation is applied after every addition, in order.
+pub fn sum_with_overflow(s: &Series, policy: OverflowPolicy) -> PolarsResult<Series> {
+    use DataType::*;
+    if policy == OverflowPolicy::Wrap || !s.dtype().is_integer() {
+        return Ok(s.sum_reduce()?.into_series(s.name().clone()));
+    }
+
+    let (dtype, policy) = match (s.dtype(), policy) {
+        (dt, OverflowPolicy::Upcast) => (upcast_dtype(dt), OverflowPolicy::Error),
+        (Int8 | UInt8 | Int16 | UInt16, policy) => (Int64, policy),
+        (dt, policy) => (dt.clone(), policy),
+    };
+    let s = s.strict_cast(&dtype)?;
+
+    with_match_physical_integer_polars_type!(&dtype, |$T| {
+        let ca: &ChunkedArray<$T> = s.as_ref().as_ref().as_ref();
+
+        sum_ca(ca, policy).map(|ca| ca.into_series())
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_arithmetic_with_overflow_policies() -> PolarsResult<()> {
+        let lhs = Series::new("a".into(), &[Some(i32::MAX), None, Some(i32::MIN), Some(1)]);
+        let rhs = Series::new("b".into(), &[Some(1i32), Some(1), Some(-1), Some(2)]);
+        let add = |policy| arithmetic_with_overflow(&lhs, &rhs, OverflowOperator::Add, policy);
+
+        let out = add(OverflowPolicy::Wrap)?;
+        let expected = [Some(i32::MIN), None, Some(i32::MAX), Some(3)];
+        assert_eq!(Vec::from(out.i32()?), &expected);
+        let out = add(OverflowPolicy::Saturate)?;
+        let expected = [Some(i32::MAX), None, Some(i32::MIN), Some(3)];
+        assert_eq!(Vec::from(out.i32()?), &expected);
+        assert!(add(OverflowPolicy::Error).is_err());
+        let out = add(OverflowPolicy::Upcast)?;
+        let expected = [
+            Some(i32::MAX as i64 + 1),
+            None,
+            Some(i32::MIN as i64 - 1),
+            Some(3),
+        ];
+        assert_eq!(Vec::from(out.i64()?), &expected);
+
+        // Nulls never overflow.
+        let lhs = Series::new("a".into(), &[None, Some(1u32)]);
+        let rhs = Series::new("b".into(), &[Some(u32::MAX), Some(1)]);
+        let out =
+            arithmetic_with_overflow(&lhs, &rhs, OverflowOperator::Add, OverflowPolicy::Error)?;
+        assert_eq!(Vec::from(out.u32()?), &[None, Some(2)]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_arithmetic_with_overflow_broadcast_and_mixed_widths() -> PolarsResult<()> {
+        let lhs = Series::new("a".into(), &[i64::MAX, 2, -3]);
+        let rhs = Series::new("b".into(), &[2i32]);
+        let mul =
+            |lhs, rhs, policy| arithmetic_with_overflow(lhs, rhs, OverflowOperator::Mul, policy);
+
+        let out = mul(&lhs, &rhs, OverflowPolicy::Saturate)?;
+        assert_eq!(Vec::from(out.i64()?), &[Some(i64::MAX), Some(4), Some(-6)]);
+        let out = mul(&rhs, &lhs, OverflowPolicy::Saturate)?;
+        assert_eq!(Vec::from(out.i64()?), &[Some(i64::MAX), Some(4), Some(-6)]);
+        assert!(mul(&rhs, &lhs, OverflowPolicy::Error).is_err());
+
+        // `UInt32` and `Int32` are computed as `Int64`.
+        let lhs = Series::new("a".into(), &[u32::MAX, 1]);
+        let rhs = Series::new("b".into(), &[i32::MAX, 2]);
+        let out = mul(&lhs, &rhs, OverflowPolicy::Error)?;
+        let expected = [Some(u32::MAX as i64 * i32::MAX as i64), Some(2)];
+        assert_eq!(Vec::from(out.i64()?), &expected);
+
+        let short = Series::new("c".into(), &[1i32, 2, 3]);
+        assert!(mul(&lhs, &short, OverflowPolicy::Error).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_arithmetic_with_overflow_non_integer_supertype() -> PolarsResult<()> {
+        let lhs = Series::new("a".into(), &[1i64]);
+        let rhs = Series::new("b".into(), &[u64::MAX]);
+        for policy in [
+            OverflowPolicy::Saturate,
+            OverflowPolicy::Error,
+            OverflowPolicy::Upcast,
+        ] {
+            let out = arithmetic_with_overflow(&lhs, &rhs, OverflowOperator::Add, policy);
+            assert!(matches!(out, Err(PolarsError::InvalidOperation(_))));
+        }
+
+        // Floats aren't affected by the policy.
+        let rhs = Series::new("b".into(), &[0.5f64]);
+        let out =
+            arithmetic_with_overflow(&lhs, &rhs, OverflowOperator::Add, OverflowPolicy::Error)?;
+        assert_eq!(Vec::from(out.f64()?), &[Some(1.5)]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_sum_with_overflow() -> PolarsResult<()> {
+        let s = Series::new("a".into(), &[Some(i32::MAX), None, Some(1)]);
+        let sum = |policy| sum_with_overflow(&s, policy);
+
+        assert_eq!(sum(OverflowPolicy::Saturate)?.i32()?.get(0), Some(i32::MAX));
+        assert!(sum(OverflowPolicy::Error).is_err());
+        let out = sum(OverflowPolicy::Upcast)?;
+        assert_eq!(out.i64()?.get(0), Some(i32::MAX as i64 + 1));
+
+        let s = Series::new_empty("a".into(), &DataType::UInt64);
+        let out = sum_with_overflow(&s, OverflowPolicy::Error)?;
+        assert_eq!(out.u64()?.get(0), Some(0));
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "dtype-i8")]
+    fn test_sum_with_overflow_small_integers() -> PolarsResult<()> {
+        // Like the regular sum, small integers are summed as `Int64`.
+        let s = Series::new("a".into(), &[i8::MAX, i8::MAX]);
+        let out = sum_with_overflow(&s, OverflowPolicy::Error)?;
+        assert_eq!(out.i64()?.get(0), Some(2 * i8::MAX as i64));
+        Ok(())
+    }
+}
@@ -25,6 +25,8 @@ pub enum RankMethod {
 pub struct RankOptions {
     pub method: RankMethod,
     pub descending: bool,
+    /// Scale the ranks to `(0, 1]` by dividing them by the highest possible rank.
+    pub pct: bool,
 }
 
 impl Default for RankOptions {
@@ -32,6 +34,7 @@ impl Default for RankOptions {
         Self {
             method: RankMethod::Dense,
             descending: false,
+            pct: false,
         }
     }
 }
@@ -191,9 +194,29 @@ fn rank(s: &Series, method: RankMethod, descending: bool, seed: Option<u64>) ->
     }
 }
 
+/// Divide the ranks by the number of non-null values, or by the number of distinct values for
+/// [`RankMethod::Dense`].
+fn rank_pct(ranks: Series, method: RankMethod) -> Series {
+    let ranks = ranks.cast(&DataType::Float64).unwrap();
+    let ca = ranks.f64().unwrap();
+    let max_rank = match method {
+        RankMethod::Dense => ca.max(),
+        _ => Some((ca.len() - ca.null_count()) as f64),
+    };
+    match max_rank {
+        Some(max_rank) if max_rank > 0.0 => (ca / max_rank).into_series(),
+        _ => ranks,
+    }
+}
+
 pub trait SeriesRank: SeriesSealed {
     fn rank(&self, options: RankOptions, seed: Option<u64>) -> Series {
-        rank(self.as_series(), options.method, options.descending, seed)
+        let out = rank(self.as_series(), options.method, options.descending, seed);
+        if options.pct {
+            rank_pct(out, options.method)
+        } else {
+            out
+        }
     }
 }
 
@@ -344,4 +367,52 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn test_rank_pct() -> PolarsResult<()> {
+        let s = Series::new("a".into(), &[Some(1), Some(2), None, Some(2), Some(4)]);
+        let pct_rank = |method| {
+            let options = RankOptions {
+                method,
+                pct: true,
+                ..Default::default()
+            };
+            s.rank(options, None)
+        };
+
+        let out = pct_rank(RankMethod::Average);
+        assert_eq!(out.name().as_str(), "a");
+        assert_eq!(
+            Vec::from(out.f64()?),
+            &[Some(0.25), Some(0.625), None, Some(0.625), Some(1.0)]
+        );
+        let out = pct_rank(RankMethod::Min);
+        assert_eq!(
+            Vec::from(out.f64()?),
+            &[Some(0.25), Some(0.5), None, Some(0.5), Some(1.0)]
+        );
+        // Dense ranks are scaled by the number of distinct values.
+        let out = pct_rank(RankMethod::Dense);
+        assert_eq!(
+            Vec::from(out.f64()?),
+            &[
+                Some(1.0 / 3.0),
+                Some(2.0 / 3.0),
+                None,
+                Some(2.0 / 3.0),
+                Some(1.0)
+            ]
+        );
+
+        let s = UInt32Chunked::new("".into(), &[None, None]).into_series();
+        let options = RankOptions {
+            method: RankMethod::Ordinal,
+            pct: true,
+            ..Default::default()
+        };
+        let out = s.rank(options, None);
+        assert_eq!(out.dtype(), &DataType::Float64);
+        assert_eq!(out.null_count(), 2);
+        Ok(())
+    }
 }
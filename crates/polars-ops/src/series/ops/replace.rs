@@ -16,11 +16,12 @@ pub fn replace(s: &Series, old: &Series, new: &Series) -> PolarsResult<Series> {
     let old = cast_old_to_series_dtype(old, dtype)?;
     let new = new.strict_cast(dtype)?;
 
-    if new.len() == 1 {
+    let out = if new.len() == 1 {
         replace_by_single(s, &old, &new, s)
     } else {
         replace_by_multiple(s, old, new, s)
-    }
+    }?;
+    Ok(out.with_name(s.name().clone()))
 }
 
 /// Replace all values by different values.
@@ -51,17 +52,18 @@ pub fn replace_or_default(
         } else {
             default
         };
-        return Ok(out);
+        return Ok(out.with_name(s.name().clone()));
     }
 
     let old = cast_old_to_series_dtype(old, s.dtype())?;
     let new = new.cast(&return_dtype)?;
 
-    if new.len() == 1 {
+    let out = if new.len() == 1 {
         replace_by_single(s, &old, &new, &default)
     } else {
         replace_by_multiple(s, old, new, &default)
-    }
+    }?;
+    Ok(out.with_name(s.name().clone()))
 }
 
 /// Replace all values by different values.
@@ -88,11 +90,12 @@ pub fn replace_strict(
         None => new.clone(),
     };
 
-    if new.len() == 1 {
+    let out = if new.len() == 1 {
         replace_by_single_strict(s, &old, &new)
     } else {
         replace_by_multiple_strict(s, old, new)
-    }
+    }?;
+    Ok(out.with_name(s.name().clone()))
 }
 
 /// Validate the `old` input.
@@ -276,3 +279,38 @@ fn ensure_all_replaced(
     );
     Ok(())
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_replace_keeps_name() -> PolarsResult<()> {
+        let s = Series::new("a".into(), [1i32, 2, 3]);
+        let old = Series::new("old".into(), [1i32, 3]);
+
+        let out = replace(&s, &old, &Series::new("new".into(), [10i32, 30]))?;
+        assert_eq!(out.name().as_str(), "a");
+        assert_eq!(Vec::from(out.i32()?), &[Some(10), Some(2), Some(30)]);
+
+        let out = replace(&s, &old, &Series::new("new".into(), [0i32]))?;
+        assert_eq!(out.name().as_str(), "a");
+        assert_eq!(Vec::from(out.i32()?), &[Some(0), Some(2), Some(0)]);
+
+        let default = Series::new("default".into(), [-1i32]);
+        let out = replace_or_default(
+            &s,
+            &old,
+            &Series::new("new".into(), [10i32, 30]),
+            &default,
+            None,
+        )?;
+        assert_eq!(out.name().as_str(), "a");
+        assert_eq!(Vec::from(out.i32()?), &[Some(10), Some(-1), Some(30)]);
+
+        let out = replace_strict(&s, &s, &Series::new("new".into(), [3i32, 2, 1]), None)?;
+        assert_eq!(out.name().as_str(), "a");
+        assert_eq!(Vec::from(out.i32()?), &[Some(3), Some(2), Some(1)]);
+        Ok(())
+    }
+}
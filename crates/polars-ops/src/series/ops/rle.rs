@@ -3,9 +3,16 @@ use polars_core::series::IsSorted;
 
 /// Get the lengths of runs of identical values.
 pub fn rle(s: &Series) -> PolarsResult<Series> {
+    if s.is_empty() {
+        let outvals = [
+            Series::new_empty(PlSmallStr::from_static("len"), &IDX_DTYPE),
+            Series::new_empty(PlSmallStr::from_static("value"), s.dtype()),
+        ];
+        return Ok(StructChunked::from_series(s.name().clone(), &outvals)?.into_series());
+    }
     let (s1, s2) = (s.slice(0, s.len() - 1), s.slice(1, s.len()));
     let s_neq = s1.not_equal_missing(&s2)?;
-    let n_runs = s_neq.sum().ok_or_else(|| polars_err!(InvalidOperation: "could not evaluate 'rle_id' on series of dtype: {}", s.dtype()))? + 1;
+    let n_runs = s_neq.sum().ok_or_else(|| polars_err!(InvalidOperation: "could not evaluate 'rle' on series of dtype: {}", s.dtype()))? + 1;
 
     let mut lengths = Vec::<IdxSize>::with_capacity(n_runs as usize);
     lengths.push(1);
@@ -54,3 +61,22 @@ pub fn rle_id(s: &Series) -> PolarsResult<Series> {
         .with_sorted_flag(IsSorted::Ascending)
         .into_series())
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_rle_empty() -> PolarsResult<()> {
+        let s = Series::new_empty("a".into(), &DataType::Int32);
+
+        let out = rle(&s)?;
+        assert_eq!(out.len(), 0);
+        let fields = out.struct_()?.fields_as_series();
+        assert_eq!(fields[0].dtype(), &IDX_DTYPE);
+        assert_eq!(fields[1].dtype(), &DataType::Int32);
+
+        assert_eq!(rle_id(&s)?.len(), 0);
+        Ok(())
+    }
+}
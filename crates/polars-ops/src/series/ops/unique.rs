@@ -39,6 +39,12 @@ pub fn unique_counts(s: &Series) -> PolarsResult<Series> {
             DataType::String => {
                 Ok(unique_counts_helper(s.str().unwrap().into_iter()).into_series())
             },
+            DataType::Binary => {
+                Ok(unique_counts_helper(s.binary().unwrap().into_iter()).into_series())
+            },
+            DataType::Boolean => {
+                Ok(unique_counts_helper(s.bool().unwrap().into_iter()).into_series())
+            },
             DataType::Null => {
                 let ca = if s.is_empty() {
                     IdxCa::new(s.name().clone(), [] as [IdxSize; 0])
@@ -53,3 +59,20 @@ pub fn unique_counts(s: &Series) -> PolarsResult<Series> {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_unique_counts_bool_binary() -> PolarsResult<()> {
+        let s = Series::new("a".into(), &[Some(true), None, Some(true), Some(false)]);
+        let out = unique_counts(&s)?;
+        assert_eq!(Vec::from(out.idx()?), &[Some(2), Some(1), Some(1)]);
+
+        let s = Series::new("a".into(), &[b"x".as_slice(), b"y", b"x"]);
+        let out = unique_counts(&s)?;
+        assert_eq!(Vec::from(out.idx()?), &[Some(2), Some(1)]);
+        Ok(())
+    }
+}
@@ -59,7 +59,7 @@ impl OocState {
         // start IO thread
         let mut iot = self.io_thread.lock().unwrap();
         if iot.is_none() {
-            *iot = Some(IOThread::try_new(Arc::new(spill_schema), "group_by").unwrap());
+            *iot = Some(IOThread::try_new(Arc::new(spill_schema), "group_by")?);
         }
         Ok(())
     }
@@ -4,9 +4,7 @@ use std::thread::JoinHandle;
 
 use crossbeam_channel::{bounded, Receiver, Sender};
 use polars_core::prelude::*;
-use polars_io::parquet::write::{
-    BatchedWriter, ParquetWriteOptions, ParquetWriter, RowGroupIterColumns,
-};
+use polars_io::parquet::write::{BatchedWriter, ParquetWriteOptions, RowGroupIterColumns};
 
 use crate::executors::sinks::output::file_sink::{init_writer_thread, FilesSink, SinkWriter};
 use crate::operators::{DataChunk, FinalizedSink, PExecutionContext, Sink, SinkResult};
@@ -61,11 +59,8 @@ impl ParquetSink {
     #[allow(clippy::new_ret_no_self)]
     pub fn new(path: &Path, options: ParquetWriteOptions, schema: &Schema) -> PolarsResult<Self> {
         let file = std::fs::File::create(path)?;
-        let writer = ParquetWriter::new(file)
-            .with_compression(options.compression)
-            .with_data_page_size(options.data_page_size)
-            .with_statistics(options.statistics)
-            .with_row_group_size(options.row_group_size)
+        let writer = options
+            .to_writer(file)
             // This is important! Otherwise we will deadlock
             // See: #7074
             .set_parallel(false)
@@ -152,11 +147,8 @@ impl ParquetCloudSink {
     ) -> PolarsResult<FilesSink> {
         polars_io::pl_async::get_runtime().block_on_potential_spawn(async {
             let cloud_writer = polars_io::cloud::CloudWriter::new(uri, cloud_options).await?;
-            let writer = ParquetWriter::new(cloud_writer)
-                .with_compression(parquet_options.compression)
-                .with_data_page_size(parquet_options.data_page_size)
-                .with_statistics(parquet_options.statistics)
-                .with_row_group_size(parquet_options.row_group_size)
+            let writer = parquet_options
+                .to_writer(cloud_writer)
                 // This is important! Otherwise we will deadlock
                 // See: #7074
                 .set_parallel(false)
@@ -191,7 +191,7 @@ where
                     match &file_type {
                         #[cfg(feature = "parquet")]
                         FileType::Parquet(options) => {
-                            Box::new(ParquetSink::new(path, *options, input_schema.as_ref())?)
+                            Box::new(ParquetSink::new(path, options.clone(), input_schema.as_ref())?)
                                 as Box<dyn SinkTrait>
                         },
                         #[cfg(feature = "ipc")]
@@ -227,7 +227,7 @@ where
                         FileType::Parquet(parquet_options) => Box::new(ParquetCloudSink::new(
                             uri.as_ref().as_str(),
                             cloud_options.as_ref(),
-                            *parquet_options,
+                            parquet_options.clone(),
                             lp_arena.get(*input).schema(lp_arena).as_ref(),
                         )?)
                             as Box<dyn SinkTrait>,
@@ -57,6 +57,43 @@ impl Expr {
         binary_expr(self, Operator::FloorDivide, rhs)
     }
 
+    fn arithmetic_with_overflow(
+        self,
+        rhs: Self,
+        op: OverflowOperator,
+        policy: OverflowPolicy,
+    ) -> Self {
+        if policy == OverflowPolicy::Wrap {
+            let op = match op {
+                OverflowOperator::Add => Operator::Plus,
+                OverflowOperator::Sub => Operator::Minus,
+                OverflowOperator::Mul => Operator::Multiply,
+            };
+            return binary_expr(self, op, rhs);
+        }
+        self.map_many_private(
+            FunctionExpr::ArithmeticWithOverflow { op, policy },
+            &[rhs],
+            false,
+            Some(Default::default()),
+        )
+    }
+
+    /// Add `rhs` to `self`, handling integer overflow according to `policy`.
+    pub fn add_with_overflow(self, rhs: Self, policy: OverflowPolicy) -> Self {
+        self.arithmetic_with_overflow(rhs, OverflowOperator::Add, policy)
+    }
+
+    /// Subtract `rhs` from `self`, handling integer overflow according to `policy`.
+    pub fn sub_with_overflow(self, rhs: Self, policy: OverflowPolicy) -> Self {
+        self.arithmetic_with_overflow(rhs, OverflowOperator::Sub, policy)
+    }
+
+    /// Multiply `self` by `rhs`, handling integer overflow according to `policy`.
+    pub fn mul_with_overflow(self, rhs: Self, policy: OverflowPolicy) -> Self {
+        self.arithmetic_with_overflow(rhs, OverflowOperator::Mul, policy)
+    }
+
     /// Raise expression to the power `exponent`
     pub fn pow<E: Into<Expr>>(self, exponent: E) -> Self {
         self.map_many_private(
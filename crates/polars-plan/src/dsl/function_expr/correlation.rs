@@ -26,6 +26,11 @@ impl Display for CorrelationMethod {
 }
 
 pub(super) fn corr(s: &[Series], ddof: u8, method: CorrelationMethod) -> PolarsResult<Series> {
+    polars_ensure!(
+        s[0].len() == s[1].len(),
+        ShapeMismatch: "{} requires columns of equal length, got {} and {}",
+        method, s[0].len(), s[1].len()
+    );
     match method {
         CorrelationMethod::Pearson => pearson_corr(s, ddof),
         #[cfg(all(feature = "rank", feature = "propagate_nans"))]
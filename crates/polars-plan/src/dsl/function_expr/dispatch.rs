@@ -192,6 +192,18 @@ pub(super) fn negate(s: &Series) -> PolarsResult<Series> {
     polars_ops::series::negate(s)
 }
 
+pub(super) fn arithmetic_with_overflow(
+    s: &[Series],
+    op: OverflowOperator,
+    policy: OverflowPolicy,
+) -> PolarsResult<Series> {
+    polars_ops::series::arithmetic_with_overflow(&s[0], &s[1], op, policy)
+}
+
+pub(super) fn sum_with_overflow(s: &Series, policy: OverflowPolicy) -> PolarsResult<Series> {
+    polars_ops::series::sum_with_overflow(s, policy)
+}
+
 pub(super) fn extend_constant(s: &[Series]) -> PolarsResult<Series> {
     let value = &s[1];
     let n = &s[2];
@@ -132,6 +132,11 @@ pub enum FunctionExpr {
     #[cfg(feature = "abs")]
     Abs,
     Negate,
+    ArithmeticWithOverflow {
+        op: OverflowOperator,
+        policy: OverflowPolicy,
+    },
+    SumWithOverflow(OverflowPolicy),
     #[cfg(feature = "hist")]
     Hist {
         bin_count: Option<usize>,
@@ -413,6 +418,11 @@ impl Hash for FunctionExpr {
             #[cfg(feature = "abs")]
             Abs => {},
             Negate => {},
+            ArithmeticWithOverflow { op, policy } => {
+                op.hash(state);
+                policy.hash(state);
+            },
+            SumWithOverflow(policy) => policy.hash(state),
             NullCount => {},
             #[cfg(feature = "arg_where")]
             ArgWhere => {},
@@ -608,6 +618,12 @@ impl Display for FunctionExpr {
             #[cfg(feature = "abs")]
             Abs => "abs",
             Negate => "negate",
+            ArithmeticWithOverflow { op, .. } => match op {
+                OverflowOperator::Add => "add_with_overflow",
+                OverflowOperator::Sub => "sub_with_overflow",
+                OverflowOperator::Mul => "mul_with_overflow",
+            },
+            SumWithOverflow(_) => "sum_with_overflow",
             NullCount => "null_count",
             Pow(func) => return write!(f, "{func}"),
             #[cfg(feature = "row_hash")]
@@ -876,6 +892,10 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn SeriesUdf>> {
             #[cfg(feature = "abs")]
             Abs => map!(abs::abs),
             Negate => map!(dispatch::negate),
+            ArithmeticWithOverflow { op, policy } => {
+                map_as_slice!(dispatch::arithmetic_with_overflow, op, policy)
+            },
+            SumWithOverflow(policy) => map!(dispatch::sum_with_overflow, policy),
             NullCount => {
                 let f = |s: &mut [Series]| {
                     let s = &s[0];
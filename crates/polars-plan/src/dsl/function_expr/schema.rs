@@ -37,6 +37,17 @@ impl FunctionExpr {
             #[cfg(feature = "abs")]
             Abs => mapper.with_same_dtype(),
             Negate => mapper.with_same_dtype(),
+            ArithmeticWithOverflow { op, policy } => {
+                let supertype = mapper.map_to_supertype()?;
+                ensure_integer_supertype(
+                    fields[0].dtype(),
+                    fields[1].dtype(),
+                    supertype.dtype(),
+                    *op,
+                )?;
+                mapper.with_dtype(overflow_dtype(supertype.dtype(), *policy, false))
+            },
+            SumWithOverflow(policy) => mapper.map_dtype(|dt| overflow_dtype(dt, *policy, true)),
             NullCount => mapper.with_dtype(IDX_DTYPE),
             Pow(pow_function) => match pow_function {
                 PowFunction::Generic => mapper.pow_dtype(),
@@ -91,6 +102,7 @@ impl FunctionExpr {
             #[cfg(feature = "rank")]
             Rank { options, .. } => mapper.with_dtype(match options.method {
                 RankMethod::Average => DataType::Float64,
+                _ if options.pct => DataType::Float64,
                 _ => IDX_DTYPE,
             }),
             #[cfg(feature = "dtype-struct")]
@@ -220,9 +232,9 @@ impl FunctionExpr {
             #[cfg(feature = "cov")]
             Correlation { .. } => mapper.map_to_float_dtype(),
             #[cfg(feature = "peaks")]
-            PeakMin => mapper.with_same_dtype(),
+            PeakMin => mapper.with_dtype(DataType::Boolean),
             #[cfg(feature = "peaks")]
-            PeakMax => mapper.with_same_dtype(),
+            PeakMax => mapper.with_dtype(DataType::Boolean),
             #[cfg(feature = "cutqcut")]
             Cut {
                 include_breaks: false,
@@ -565,6 +577,23 @@ impl<'a> FieldsMapper<'a> {
     }
 }
 
+/// Output dtype of the arithmetic and sums that take an [`OverflowPolicy`].
+fn overflow_dtype(dtype: &DataType, policy: OverflowPolicy, is_sum: bool) -> DataType {
+    use DataType::*;
+    match dtype {
+        dt if dt.is_integer() && policy == OverflowPolicy::Upcast => {
+            if dt.is_signed_integer() {
+                Int64
+            } else {
+                UInt64
+            }
+        },
+        Int8 | UInt8 | Int16 | UInt16 if is_sum => Int64,
+        Boolean if is_sum => IDX_DTYPE,
+        dt => dt.clone(),
+    }
+}
+
 pub(crate) fn args_to_supertype<D: AsRef<DataType>>(dtypes: &[D]) -> PolarsResult<DataType> {
     let mut st = dtypes[0].as_ref().clone();
     for dt in &dtypes[1..] {
@@ -14,6 +14,10 @@ pub fn repeat<E: Into<Expr>>(value: E, n: Expr) -> Expr {
         let n = first_value.extract::<usize>().ok_or_else(
             || polars_err!(ComputeError: "could not parse value '{}' as a size.", first_value),
         )?;
+        polars_ensure!(
+            !s.is_empty() || n == 0,
+            ComputeError: "cannot repeat an empty value {} times", n
+        );
         Ok(Some(s.new_from_index(0, n)))
     };
     apply_binary(value.into(), n, function, GetOutput::same_type())
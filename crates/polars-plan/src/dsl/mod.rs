@@ -1253,6 +1253,67 @@ impl Expr {
         self.dot_impl(other.into())
     }
 
+    /// Compute the weighted mean of this expression, using `weights` as the weight of
+    /// every value. Values or weights that are null are ignored.
+    pub fn weighted_mean<E: Into<Expr>>(self, weights: E) -> Expr {
+        let weights = weights.into();
+        // Only weights that belong to a valid value contribute to the denominator.
+        let valid = self
+            .clone()
+            .is_not_null()
+            .and(weights.clone().is_not_null());
+        let weighted_sum = (self * weights.clone()).sum();
+        let weight_sum = weights.filter(valid).sum();
+        binary_expr(weighted_sum, Operator::TrueDivide, weight_sum)
+    }
+
+    /// Compute the weighted sum of this expression, using `weights` as the weight of
+    /// every value. Values or weights that are null are ignored.
+    ///
+    /// If both the values and the weights are integers, the products and the sum wrap
+    /// around on overflow.
+    pub fn weighted_sum<E: Into<Expr>>(self, weights: E) -> Expr {
+        (self * weights.into()).sum()
+    }
+
+    /// Compute the weighted quantile of this expression, using `weights` as the weight of
+    /// every value. This is the smallest value for which the cumulative weight of all values
+    /// up to and including it reaches `quantile` times the total weight.
+    ///
+    /// Errors if `quantile` is not between 0 and 1 or if any weight is negative. Values or
+    /// weights that are null are ignored.
+    #[cfg(feature = "cum_agg")]
+    pub fn weighted_quantile<E: Into<Expr>>(self, weights: E, quantile: f64) -> Expr {
+        let weights = weights.into();
+        let valid = self
+            .clone()
+            .is_not_null()
+            .and(weights.clone().is_not_null());
+        let values = self.filter(valid.clone());
+        let weights = weights.filter(valid).cast(DataType::Float64).map(
+            move |s| {
+                polars_ensure!(
+                    (0.0..=1.0).contains(&quantile),
+                    ComputeError: "quantile should be between 0.0 and 1.0"
+                );
+                polars_ensure!(
+                    !s.f64()?.min().is_some_and(|min| min < 0.0),
+                    ComputeError: "weighted_quantile requires non-negative weights"
+                );
+                Ok(Some(s))
+            },
+            GetOutput::same_type(),
+        );
+        let cum_weights = weights
+            .sort_by([values.clone()], Default::default())
+            .cum_sum(false);
+        // Normalize by the last cumulative weight so that a quantile of 1 always matches.
+        let reached = cum_weights
+            .clone()
+            .gt_eq(lit(quantile) * cum_weights.last());
+        values.sort(Default::default()).filter(reached).first()
+    }
+
     #[cfg(feature = "mode")]
     /// Compute the mode(s) of this column. This is the most occurring value.
     pub fn mode(self) -> Expr {
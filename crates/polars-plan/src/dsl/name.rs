@@ -88,7 +88,7 @@ impl ExprNameNameSpace {
                         .collect();
                     Ok(DataType::Struct(fields))
                 },
-                _ => panic!("Only struct dtype is supported for `map_fields`."),
+                _ => polars_bail!(op = "map_fields", got = dt, expected = "Struct"),
             }),
         )
     }
@@ -62,6 +62,19 @@ impl Expr {
         AggExpr::Sum(Arc::new(self)).into()
     }
 
+    /// Reduce groups to the sum of all the values, handling integer overflow
+    /// according to `policy`.
+    pub fn sum_with_overflow(self, policy: OverflowPolicy) -> Self {
+        if policy == OverflowPolicy::Wrap {
+            return self.sum();
+        }
+        self.apply_private(FunctionExpr::SumWithOverflow(policy))
+            .with_function_options(|mut options| {
+                options.flags |= FunctionFlags::RETURNS_SCALAR;
+                options
+            })
+    }
+
     /// Compute the histogram of a dataset.
     #[cfg(feature = "hist")]
     pub fn hist(
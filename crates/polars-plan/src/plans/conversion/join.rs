@@ -1,6 +1,7 @@
 use arrow::legacy::error::PolarsResult;
 use either::Either;
 use polars_core::error::feature_gated;
+use polars_core::utils::get_numeric_upcast_supertype_lossless;
 
 use super::*;
 use crate::dsl::Expr;
@@ -21,8 +22,8 @@ fn check_join_keys(keys: &[Expr]) -> PolarsResult<()> {
 pub fn resolve_join(
     input_left: Either<Arc<DslPlan>, Node>,
     input_right: Either<Arc<DslPlan>, Node>,
-    left_on: Vec<Expr>,
-    right_on: Vec<Expr>,
+    mut left_on: Vec<Expr>,
+    mut right_on: Vec<Expr>,
     predicates: Vec<Expr>,
     mut options: Arc<JoinOptions>,
     ctxt: &mut DslConversionContext,
@@ -74,13 +75,29 @@ pub fn resolve_join(
         );
     }
 
-    let input_left = input_left.map_right(Ok).right_or_else(|input| {
+    let mut input_left = input_left.map_right(Ok).right_or_else(|input| {
         to_alp_impl(owned(input), ctxt).map_err(|e| e.context(failed_input!(join left)))
     })?;
-    let input_right = input_right.map_right(Ok).right_or_else(|input| {
+    let mut input_right = input_right.map_right(Ok).right_or_else(|input| {
         to_alp_impl(owned(input), ctxt).map_err(|e| e.context(failed_input!(join right)))
     })?;
 
+    // The keys of an asof join must be sorted and match the `by` columns exactly, so those
+    // are left to the dtype checks of the asof join itself.
+    #[cfg(feature = "asof_join")]
+    let coerce_keys = options.args.coerce_keys && !matches!(options.args.how, JoinType::AsOf(_));
+    #[cfg(not(feature = "asof_join"))]
+    let coerce_keys = options.args.coerce_keys;
+    if coerce_keys {
+        coerce_join_keys(
+            &mut input_left,
+            &mut input_right,
+            &mut left_on,
+            &mut right_on,
+            ctxt,
+        )?;
+    }
+
     let schema_left = ctxt.lp_arena.get(input_left).schema(ctxt.lp_arena);
     let schema_right = ctxt.lp_arena.get(input_right).schema(ctxt.lp_arena);
 
@@ -131,6 +148,87 @@ pub fn resolve_join(
     run_conversion(lp, ctxt, "join")
 }
 
+/// Cast join keys of different types to a common type:
+/// - numeric keys are cast to a supertype if that can be done without losing values, e.g.
+///   `Int32` and `Int64` are joined as `Int64`.
+/// - categorical and enum keys joined with string keys are cast to `String`.
+///
+/// Key columns are cast in the join inputs, so that they stay simple column references and
+/// can still be coalesced. The casted dtype is therefore also the dtype of the key columns in
+/// the output. Keys without a common type are left as is and raise a dtype mismatch when the
+/// join is executed.
+fn coerce_join_keys(
+    input_left: &mut Node,
+    input_right: &mut Node,
+    left_on: &mut [Expr],
+    right_on: &mut [Expr],
+    ctxt: &mut DslConversionContext,
+) -> PolarsResult<()> {
+    let schema_left = ctxt
+        .lp_arena
+        .get(*input_left)
+        .schema(ctxt.lp_arena)
+        .into_owned();
+    let schema_right = ctxt
+        .lp_arena
+        .get(*input_right)
+        .schema(ctxt.lp_arena)
+        .into_owned();
+
+    let mut cast_left = vec![];
+    let mut cast_right = vec![];
+    for (l, r) in left_on.iter_mut().zip(right_on.iter_mut()) {
+        let (Ok(l_field), Ok(r_field)) = (
+            l.to_field(&schema_left, Context::Default),
+            r.to_field(&schema_right, Context::Default),
+        ) else {
+            // Let the schema resolution of the join raise the error.
+            continue;
+        };
+        if l_field.dtype() == r_field.dtype() {
+            continue;
+        }
+        let Some(dtype) = get_join_key_supertype(l_field.dtype(), r_field.dtype()) else {
+            continue;
+        };
+
+        for (key, key_dtype, casts) in [
+            (l, l_field.dtype(), &mut cast_left),
+            (r, r_field.dtype(), &mut cast_right),
+        ] {
+            if key_dtype == &dtype {
+                continue;
+            }
+            match key {
+                Expr::Column(name) => casts.push(col(name.clone()).strict_cast(dtype.clone())),
+                _ => *key = key.clone().strict_cast(dtype.clone()),
+            }
+        }
+    }
+
+    for (input, casts) in [(input_left, cast_left), (input_right, cast_right)] {
+        if casts.is_empty() {
+            continue;
+        }
+        let casts = to_expr_irs(casts, ctxt.expr_arena)?;
+        ctxt.conversion_optimizer
+            .fill_scratch(&casts, ctxt.expr_arena);
+        *input = IRBuilder::new(*input, ctxt.expr_arena, ctxt.lp_arena)
+            .with_columns(casts, ProjectionOptions::default())
+            .node();
+    }
+    Ok(())
+}
+
+fn get_join_key_supertype(l: &DataType, r: &DataType) -> Option<DataType> {
+    let is_categorical = |dtype: &DataType| dtype.is_categorical() || dtype.is_enum();
+    match (l, r) {
+        (l, r) if is_categorical(l) && r.is_string() => Some(DataType::String),
+        (l, r) if l.is_string() && is_categorical(r) => Some(DataType::String),
+        (l, r) => get_numeric_upcast_supertype_lossless(l, r),
+    }
+}
+
 #[cfg(feature = "iejoin")]
 impl From<InequalityOperator> for Operator {
     fn from(value: InequalityOperator) -> Self {
@@ -67,21 +67,13 @@ fn should_block_join_specific(
 }
 
 fn join_produces_null(how: &JoinType) -> LeftRight<bool> {
-    #[cfg(feature = "asof_join")]
-    {
-        match how {
-            JoinType::Left => LeftRight(false, true),
-            JoinType::Full { .. } | JoinType::Cross | JoinType::AsOf(_) => LeftRight(true, true),
-            _ => LeftRight(false, false),
-        }
-    }
-    #[cfg(not(feature = "asof_join"))]
-    {
-        match how {
-            JoinType::Left => LeftRight(false, true),
-            JoinType::Full { .. } | JoinType::Cross => LeftRight(true, true),
-            _ => LeftRight(false, false),
-        }
+    match how {
+        JoinType::Left => LeftRight(false, true),
+        // an asof join keeps every row of the left table, like a left join
+        #[cfg(feature = "asof_join")]
+        JoinType::AsOf(_) => LeftRight(false, true),
+        JoinType::Full { .. } | JoinType::Cross => LeftRight(true, true),
+        _ => LeftRight(false, false),
     }
 }
 
@@ -216,6 +208,12 @@ pub(super) fn process_join(
         {
             filter_right = true
         }
+        // filtering the right table of an asof join changes which row is
+        // the nearest match, so predicates on the right stay local.
+        #[cfg(feature = "asof_join")]
+        if matches!(&options.args.how, JoinType::AsOf(_)) {
+            filter_right = false;
+        }
         if filter_right {
             insert_and_combine_predicate(&mut pushdown_right, &predicate, expr_arena);
         }
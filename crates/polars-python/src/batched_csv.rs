@@ -144,10 +144,6 @@ impl PyBatchedCsv {
                 .map_err(PyPolarsErr::from)
         })?;
 
-        // SAFETY: same memory layout
-        let batches = unsafe {
-            std::mem::transmute::<Option<Vec<DataFrame>>, Option<Vec<PyDataFrame>>>(batches)
-        };
-        Ok(batches)
+        Ok(batches.map(|batches| batches.into_iter().map(PyDataFrame::from).collect()))
     }
 }
@@ -199,10 +199,8 @@ pub(crate) fn py_object_to_any_value<'py>(
         Python::with_gil(|py| {
             let date = UTILS
                 .bind(py)
-                .getattr(intern!(py, "datetime_to_int"))
-                .unwrap()
-                .call1((ob, intern!(py, "us")))
-                .unwrap();
+                .getattr(intern!(py, "datetime_to_int"))?
+                .call1((ob, intern!(py, "us")))?;
             let v = date.extract::<i64>()?;
             Ok(AnyValue::Datetime(v, TimeUnit::Microseconds, &None))
         })
@@ -414,7 +412,7 @@ pub(crate) fn py_object_to_any_value<'py>(
                         return get_float as InitFn;
                     }
 
-                    // Support custom subclasses of datetime/date.
+                    // Support custom subclasses of the datetime types.
                     let ancestors = ob.get_type().getattr(intern!(py, "__mro__")).unwrap();
                     let ancestors_str_iter = ancestors
                         .iter()
@@ -426,6 +424,8 @@ pub(crate) fn py_object_to_any_value<'py>(
                             // Python datetime is an instance of date.
                             "<class 'datetime.datetime'>" => return get_datetime as InitFn,
                             "<class 'datetime.date'>" => return get_date as InitFn,
+                            "<class 'datetime.time'>" => return get_time as InitFn,
+                            "<class 'datetime.timedelta'>" => return get_timedelta as InitFn,
                             _ => (),
                         }
                     }
@@ -1114,6 +1114,23 @@ impl<'py> FromPyObject<'py> for Wrap<ClosedInterval> {
     }
 }
 
+impl<'py> FromPyObject<'py> for Wrap<OverflowPolicy> {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let parsed = match &*ob.extract::<PyBackedStr>()? {
+            "wrap" => OverflowPolicy::Wrap,
+            "saturate" => OverflowPolicy::Saturate,
+            "error" => OverflowPolicy::Error,
+            "upcast" => OverflowPolicy::Upcast,
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "`overflow` must be one of {{'wrap', 'saturate', 'error', 'upcast'}}, got {v}",
+                )))
+            },
+        };
+        Ok(Wrap(parsed))
+    }
+}
+
 impl<'py> FromPyObject<'py> for Wrap<WindowMapping> {
     fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
         let parsed = match &*ob.extract::<PyBackedStr>()? {
@@ -1130,6 +1147,21 @@ impl<'py> FromPyObject<'py> for Wrap<WindowMapping> {
     }
 }
 
+impl<'py> FromPyObject<'py> for Wrap<JoinAlgorithm> {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let parsed = match &*ob.extract::<PyBackedStr>()? {
+            "auto" => JoinAlgorithm::Auto,
+            "hash" => JoinAlgorithm::Hash,
+            "sort_merge" => JoinAlgorithm::SortMerge,
+            "broadcast" => JoinAlgorithm::Broadcast,
+            v => return Err(PyValueError::new_err(format!(
+                "`algorithm` must be one of {{'auto', 'hash', 'sort_merge', 'broadcast'}}, got {v}",
+            ))),
+        };
+        Ok(Wrap(parsed))
+    }
+}
+
 impl<'py> FromPyObject<'py> for Wrap<JoinValidation> {
     fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
         let parsed = match &*ob.extract::<PyBackedStr>()? {
@@ -1253,6 +1285,19 @@ pub(crate) fn parse_parquet_compression(
     Ok(parsed)
 }
 
+#[cfg(feature = "parquet")]
+pub(crate) fn parse_parquet_column_compression(
+    column_compression: Option<Vec<(String, String)>>,
+) -> PyResult<Vec<(PlSmallStr, ParquetCompression)>> {
+    column_compression
+        .unwrap_or_default()
+        .into_iter()
+        .map(|(name, compression)| {
+            Ok((name.into(), parse_parquet_compression(&compression, None)?))
+        })
+        .collect()
+}
+
 pub(crate) fn strings_to_pl_smallstr<I, S>(container: I) -> Vec<PlSmallStr>
 where
     I: IntoIterator<Item = S>,
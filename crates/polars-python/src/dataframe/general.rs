@@ -253,16 +253,20 @@ impl PyDataFrame {
         Ok(PyDataFrame::new(df))
     }
 
-    pub fn gather(&self, indices: Wrap<Vec<IdxSize>>) -> PyResult<Self> {
+    pub fn gather(&self, py: Python, indices: Wrap<Vec<IdxSize>>) -> PyResult<Self> {
         let indices = indices.0;
         let indices = IdxCa::from_vec("".into(), indices);
-        let df = self.df.take(&indices).map_err(PyPolarsErr::from)?;
+        let df = py
+            .allow_threads(|| self.df.take(&indices))
+            .map_err(PyPolarsErr::from)?;
         Ok(PyDataFrame::new(df))
     }
 
-    pub fn gather_with_series(&self, indices: &PySeries) -> PyResult<Self> {
+    pub fn gather_with_series(&self, py: Python, indices: &PySeries) -> PyResult<Self> {
         let indices = indices.series.idx().map_err(PyPolarsErr::from)?;
-        let df = self.df.take(indices).map_err(PyPolarsErr::from)?;
+        let df = py
+            .allow_threads(|| self.df.take(indices))
+            .map_err(PyPolarsErr::from)?;
         Ok(PyDataFrame::new(df))
     }
 
@@ -304,13 +308,17 @@ impl PyDataFrame {
         PyDataFrame::new(df)
     }
 
-    pub fn is_unique(&self) -> PyResult<PySeries> {
-        let mask = self.df.is_unique().map_err(PyPolarsErr::from)?;
+    pub fn is_unique(&self, py: Python) -> PyResult<PySeries> {
+        let mask = py
+            .allow_threads(|| self.df.is_unique())
+            .map_err(PyPolarsErr::from)?;
         Ok(mask.into_series().into())
     }
 
-    pub fn is_duplicated(&self) -> PyResult<PySeries> {
-        let mask = self.df.is_duplicated().map_err(PyPolarsErr::from)?;
+    pub fn is_duplicated(&self, py: Python) -> PyResult<PySeries> {
+        let mask = py
+            .allow_threads(|| self.df.is_duplicated())
+            .map_err(PyPolarsErr::from)?;
         Ok(mask.into_series().into())
     }
 
@@ -380,6 +388,7 @@ impl PyDataFrame {
     #[cfg(feature = "pivot")]
     pub fn unpivot(
         &self,
+        py: Python,
         on: Vec<PyBackedStr>,
         index: Vec<PyBackedStr>,
         value_name: Option<&str>,
@@ -393,7 +402,9 @@ impl PyDataFrame {
             variable_name: variable_name.map(|s| s.into()),
         };
 
-        let df = self.df.unpivot2(args).map_err(PyPolarsErr::from)?;
+        let df = py
+            .allow_threads(|| self.df.unpivot2(args))
+            .map_err(PyPolarsErr::from)?;
         Ok(PyDataFrame::new(df))
     }
 
@@ -426,19 +437,22 @@ impl PyDataFrame {
 
     pub fn partition_by(
         &self,
+        py: Python,
         by: Vec<String>,
         maintain_order: bool,
         include_key: bool,
     ) -> PyResult<Vec<Self>> {
-        let out = if maintain_order {
-            self.df.partition_by_stable(by, include_key)
-        } else {
-            self.df.partition_by(by, include_key)
-        }
-        .map_err(PyPolarsErr::from)?;
+        let out = py
+            .allow_threads(|| {
+                if maintain_order {
+                    self.df.partition_by_stable(by, include_key)
+                } else {
+                    self.df.partition_by(by, include_key)
+                }
+            })
+            .map_err(PyPolarsErr::from)?;
 
-        // SAFETY: PyDataFrame is a repr(transparent) DataFrame.
-        Ok(unsafe { std::mem::transmute::<Vec<DataFrame>, Vec<PyDataFrame>>(out) })
+        Ok(out.into_iter().map(PyDataFrame::from).collect())
     }
 
     pub fn lazy(&self) -> PyLazyFrame {
@@ -541,9 +555,18 @@ impl PyDataFrame {
         self.df.shrink_to_fit();
     }
 
-    pub fn hash_rows(&mut self, k0: u64, k1: u64, k2: u64, k3: u64) -> PyResult<PySeries> {
+    pub fn hash_rows(
+        &mut self,
+        py: Python,
+        k0: u64,
+        k1: u64,
+        k2: u64,
+        k3: u64,
+    ) -> PyResult<PySeries> {
         let hb = PlRandomState::with_seeds(k0, k1, k2, k3);
-        let hash = self.df.hash_rows(Some(hb)).map_err(PyPolarsErr::from)?;
+        let hash = py
+            .allow_threads(|| self.df.hash_rows(Some(hb)))
+            .map_err(PyPolarsErr::from)?;
         Ok(hash.into_series().into())
     }
 
@@ -13,9 +13,9 @@ use pyo3::prelude::*;
 use pyo3::pybacked::PyBackedStr;
 
 use super::PyDataFrame;
-#[cfg(feature = "parquet")]
-use crate::conversion::parse_parquet_compression;
 use crate::conversion::Wrap;
+#[cfg(feature = "parquet")]
+use crate::conversion::{parse_parquet_column_compression, parse_parquet_compression};
 use crate::error::PyPolarsErr;
 use crate::file::{
     get_either_file, get_file_like, get_mmap_bytes_reader, get_mmap_bytes_reader_and_path,
@@ -381,7 +381,7 @@ impl PyDataFrame {
     }
 
     #[cfg(feature = "parquet")]
-    #[pyo3(signature = (py_f, compression, compression_level, statistics, row_group_size, data_page_size, partition_by, partition_chunk_size_bytes))]
+    #[pyo3(signature = (py_f, compression, compression_level, statistics, row_group_size, data_page_size, dictionary_encoding, column_compression, partition_by, partition_chunk_size_bytes))]
     pub fn write_parquet(
         &mut self,
         py: Python,
@@ -391,24 +391,27 @@ impl PyDataFrame {
         statistics: Wrap<StatisticsOptions>,
         row_group_size: Option<usize>,
         data_page_size: Option<usize>,
+        dictionary_encoding: bool,
+        column_compression: Option<Vec<(String, String)>>,
         partition_by: Option<Vec<String>>,
         partition_chunk_size_bytes: usize,
     ) -> PyResult<()> {
         use polars_io::partition::write_partitioned_dataset;
 
-        let compression = parse_parquet_compression(compression, compression_level)?;
+        let write_options = ParquetWriteOptions {
+            compression: parse_parquet_compression(compression, compression_level)?,
+            statistics: statistics.0,
+            row_group_size,
+            data_page_size,
+            maintain_order: true,
+            dictionary_encoding,
+            column_compression: parse_parquet_column_compression(column_compression)?,
+        };
 
         if let Some(partition_by) = partition_by {
             let path = py_f.extract::<String>(py)?;
 
             py.allow_threads(|| {
-                let write_options = ParquetWriteOptions {
-                    compression,
-                    statistics: statistics.0,
-                    row_group_size,
-                    data_page_size,
-                    maintain_order: true,
-                };
                 write_partitioned_dataset(
                     &mut self.df,
                     std::path::Path::new(path.as_str()),
@@ -424,11 +427,8 @@ impl PyDataFrame {
 
         let buf = get_file_like(py_f, true)?;
         py.allow_threads(|| {
-            ParquetWriter::new(buf)
-                .with_compression(compression)
-                .with_statistics(statistics.0)
-                .with_row_group_size(row_group_size)
-                .with_data_page_size(data_page_size)
+            write_options
+                .to_writer(buf)
                 .finish(&mut self.df)
                 .map_err(PyPolarsErr::from)
         })?;
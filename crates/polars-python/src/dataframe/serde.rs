@@ -4,6 +4,7 @@ use std::ops::Deref;
 use polars::prelude::*;
 use polars_io::mmap::ReaderBytes;
 use pyo3::prelude::*;
+use pyo3::pybacked::PyBackedBytes;
 use pyo3::types::PyBytes;
 
 use super::PyDataFrame;
@@ -20,16 +21,16 @@ impl PyDataFrame {
         IpcStreamWriter::new(&mut buf)
             .with_compat_level(CompatLevel::newest())
             .finish(&mut self.df.clone())
-            .expect("ipc writer");
+            .map_err(PyPolarsErr::from)?;
         Ok(PyBytes::new_bound(py, &buf).to_object(py))
     }
 
     #[cfg(feature = "ipc_streaming")]
     fn __setstate__(&mut self, py: Python, state: PyObject) -> PyResult<()> {
         // Used in pickle/pickling
-        match state.extract::<&PyBytes>(py) {
+        match state.extract::<PyBackedBytes>(py) {
             Ok(s) => {
-                let c = Cursor::new(s.as_bytes());
+                let c = Cursor::new(&*s);
                 let reader = IpcStreamReader::new(c);
 
                 reader
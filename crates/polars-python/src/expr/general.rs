@@ -46,6 +46,24 @@ impl PyExpr {
     fn __neg__(&self) -> PyResult<Self> {
         Ok(self.inner.clone().neg().into())
     }
+    fn add_with_overflow(&self, rhs: Self, policy: Wrap<OverflowPolicy>) -> Self {
+        self.inner
+            .clone()
+            .add_with_overflow(rhs.inner, policy.0)
+            .into()
+    }
+    fn sub_with_overflow(&self, rhs: Self, policy: Wrap<OverflowPolicy>) -> Self {
+        self.inner
+            .clone()
+            .sub_with_overflow(rhs.inner, policy.0)
+            .into()
+    }
+    fn mul_with_overflow(&self, rhs: Self, policy: Wrap<OverflowPolicy>) -> Self {
+        self.inner
+            .clone()
+            .mul_with_overflow(rhs.inner, policy.0)
+            .into()
+    }
 
     fn to_str(&self) -> String {
         format!("{:?}", self.inner)
@@ -128,6 +146,9 @@ impl PyExpr {
     fn sum(&self) -> Self {
         self.inner.clone().sum().into()
     }
+    fn sum_with_overflow(&self, policy: Wrap<OverflowPolicy>) -> Self {
+        self.inner.clone().sum_with_overflow(policy.0).into()
+    }
     fn n_unique(&self) -> Self {
         self.inner.clone().n_unique().into()
     }
@@ -718,6 +739,21 @@ impl PyExpr {
         self.inner.clone().dot(other.inner).into()
     }
 
+    fn weighted_mean(&self, weights: Self) -> Self {
+        self.inner.clone().weighted_mean(weights.inner).into()
+    }
+
+    fn weighted_sum(&self, weights: Self) -> Self {
+        self.inner.clone().weighted_sum(weights.inner).into()
+    }
+
+    fn weighted_quantile(&self, weights: Self, quantile: f64) -> Self {
+        self.inner
+            .clone()
+            .weighted_quantile(weights.inner, quantile)
+            .into()
+    }
+
     fn reinterpret(&self, signed: bool) -> Self {
         self.inner.clone().reinterpret(signed).into()
     }
@@ -746,10 +782,18 @@ impl PyExpr {
         self.inner.clone().upper_bound().into()
     }
 
-    fn rank(&self, method: Wrap<RankMethod>, descending: bool, seed: Option<u64>) -> Self {
+    #[pyo3(signature = (method, descending, seed, pct))]
+    fn rank(
+        &self,
+        method: Wrap<RankMethod>,
+        descending: bool,
+        seed: Option<u64>,
+        pct: bool,
+    ) -> Self {
         let options = RankOptions {
             method: method.0,
             descending,
+            pct,
         };
         self.inner.clone().rank(options, seed).into()
     }
@@ -113,35 +113,21 @@ pub fn col(name: &str) -> PyExpr {
 
 #[pyfunction]
 pub fn collect_all(lfs: Vec<PyLazyFrame>, py: Python) -> PyResult<Vec<PyDataFrame>> {
-    use polars_core::utils::rayon::prelude::*;
-
-    let out = py.allow_threads(|| {
-        polars_core::POOL.install(|| {
-            lfs.par_iter()
-                .map(|lf| {
-                    let df = lf.ldf.clone().collect()?;
-                    Ok(PyDataFrame::new(df))
-                })
-                .collect::<polars_core::error::PolarsResult<Vec<_>>>()
-                .map_err(PyPolarsErr::from)
-        })
-    });
+    let lfs = lfs.into_iter().map(|lf| lf.ldf).collect::<Vec<_>>();
+    let out = py
+        .allow_threads(|| dsl::collect_all(lfs))
+        .map_err(PyPolarsErr::from)?;
 
-    Ok(out?)
+    Ok(out.into_iter().map(PyDataFrame::new).collect())
 }
 
 #[pyfunction]
 pub fn collect_all_with_callback(lfs: Vec<PyLazyFrame>, lambda: PyObject) {
-    use polars_core::utils::rayon::prelude::*;
+    let lfs = lfs.into_iter().map(|lf| lf.ldf).collect::<Vec<_>>();
 
     polars_core::POOL.spawn(move || {
-        let result = lfs
-            .par_iter()
-            .map(|lf| {
-                let df = lf.ldf.clone().collect()?;
-                Ok(PyDataFrame::new(df))
-            })
-            .collect::<polars_core::error::PolarsResult<Vec<_>>>()
+        let result = dsl::collect_all(lfs)
+            .map(|dfs| dfs.into_iter().map(PyDataFrame::new).collect::<Vec<_>>())
             .map_err(PyPolarsErr::from);
 
         Python::with_gil(|py| match result {
@@ -5,6 +5,7 @@ use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
 
 use crate::conversion::Wrap;
+use crate::error::PyPolarsErr;
 
 #[pyfunction]
 pub fn get_index_type(py: Python) -> PyObject {
@@ -16,6 +17,12 @@ pub fn thread_pool_size() -> usize {
     POOL.current_num_threads()
 }
 
+#[pyfunction]
+pub fn set_num_threads(n: usize) -> PyResult<()> {
+    polars_core::set_num_threads(n).map_err(PyPolarsErr::from)?;
+    Ok(())
+}
+
 #[pyfunction]
 pub fn set_float_fmt(fmt: &str) -> PyResult<()> {
     let fmt = match fmt {
@@ -71,6 +71,46 @@ pub(crate) fn series_to_stream<'py>(
     PyCapsule::new_bound(py, stream, Some(stream_capsule_name))
 }
 
+/// A single Arrow array that can be exported via PyCapsules according to the Arrow PyCapsule
+/// Interface.
+#[pyclass]
+pub struct PyArrowArray {
+    field: ArrowField,
+    array: ArrayRef,
+}
+
+impl PyArrowArray {
+    pub(crate) fn new(field: ArrowField, array: ArrayRef) -> Self {
+        Self { field, array }
+    }
+}
+
+// The capsule owns the exported schema, which is only moved out by the consumer.
+#[repr(transparent)]
+struct ExportedSchema(ffi::ArrowSchema);
+
+unsafe impl Send for ExportedSchema {}
+
+#[pymethods]
+impl PyArrowArray {
+    #[allow(unused_variables)]
+    #[pyo3(signature = (requested_schema=None))]
+    fn __arrow_c_array__<'py>(
+        &self,
+        py: Python<'py>,
+        requested_schema: Option<PyObject>,
+    ) -> PyResult<(Bound<'py, PyCapsule>, Bound<'py, PyCapsule>)> {
+        let schema = ExportedSchema(ffi::export_field_to_c(&self.field));
+        let array = ffi::export_array_to_c(self.array.clone());
+        let schema_capsule_name = CString::new("arrow_schema").unwrap();
+        let array_capsule_name = CString::new("arrow_array").unwrap();
+        Ok((
+            PyCapsule::new_bound(py, schema, Some(schema_capsule_name))?,
+            PyCapsule::new_bound(py, array, Some(array_capsule_name))?,
+        ))
+    }
+}
+
 pub(crate) fn dataframe_to_stream<'py>(
     df: &'py DataFrame,
     py: Python<'py>,
@@ -285,8 +285,12 @@ where
 {
     let ca: &ChunkedArray<T> = s.as_ref().as_ref();
     if s.null_count() == 0 {
-        let values = ca.into_no_null_iter();
-        PyArray1::<T::Native>::from_iter_bound(py, values).into_py(py)
+        // Copy the buffers chunk by chunk rather than element by element.
+        let mut values = Vec::with_capacity(ca.len());
+        for slice in ca.data_views() {
+            values.extend_from_slice(slice);
+        }
+        PyArray1::<T::Native>::from_vec_bound(py, values).into_py(py)
     } else {
         let mapper = |opt_v: Option<T::Native>| match opt_v {
             Some(v) => NumCast::from(v).unwrap(),
@@ -1,3 +1,5 @@
+use std::time::Duration;
+
 use polars::prelude::*;
 use pyo3::prelude::*;
 
@@ -35,7 +37,17 @@ impl PyInProcessQuery {
     }
 
     pub fn fetch_blocking(&self, py: Python) -> PyResult<PyDataFrame> {
-        let out = py.allow_threads(|| self.ipq.fetch_blocking().map_err(PyPolarsErr::from))?;
-        Ok(out.into())
+        // Wake up regularly so Python can handle signals, cancelling the query on
+        // e.g. a KeyboardInterrupt.
+        loop {
+            let out = py.allow_threads(|| self.ipq.fetch_timeout(Duration::from_millis(100)));
+            if let Some(out) = out {
+                return Ok(out.map_err(PyPolarsErr::from)?.into());
+            }
+            if let Err(err) = py.check_signals() {
+                self.ipq.cancel();
+                return Err(err);
+            }
+        }
     }
 }
@@ -240,7 +240,7 @@ impl PyLazyFrame {
     #[cfg(feature = "parquet")]
     #[staticmethod]
     #[pyo3(signature = (source, sources, n_rows, cache, parallel, rechunk, row_index,
-        low_memory, cloud_options, use_statistics, hive_partitioning, hive_schema, try_parse_hive_dates, retries, glob, include_file_paths)
+        low_memory, cloud_options, use_statistics, hive_partitioning, hive_schema, try_parse_hive_dates, retries, glob, include_file_paths, unify_schemas)
     )]
     fn new_from_parquet(
         source: Option<PyObject>,
@@ -259,6 +259,7 @@ impl PyLazyFrame {
         retries: usize,
         glob: bool,
         include_file_paths: Option<String>,
+        unify_schemas: bool,
     ) -> PyResult<Self> {
         let parallel = parallel.0;
         let hive_schema = hive_schema.map(|s| Arc::new(s.0));
@@ -287,6 +288,7 @@ impl PyLazyFrame {
             hive_options,
             glob,
             include_file_paths: include_file_paths.map(|x| x.into()),
+            unify_schemas,
         };
 
         let sources = sources.0;
@@ -310,7 +312,7 @@ impl PyLazyFrame {
 
     #[cfg(feature = "ipc")]
     #[staticmethod]
-    #[pyo3(signature = (source, sources, n_rows, cache, rechunk, row_index, cloud_options, hive_partitioning, hive_schema, try_parse_hive_dates, retries, file_cache_ttl, include_file_paths))]
+    #[pyo3(signature = (source, sources, n_rows, cache, rechunk, row_index, cloud_options, hive_partitioning, hive_schema, try_parse_hive_dates, retries, file_cache_ttl, include_file_paths, unify_schemas))]
     fn new_from_ipc(
         source: Option<PyObject>,
         sources: Wrap<ScanSources>,
@@ -325,6 +327,7 @@ impl PyLazyFrame {
         retries: usize,
         file_cache_ttl: Option<u64>,
         include_file_paths: Option<String>,
+        unify_schemas: bool,
     ) -> PyResult<Self> {
         let row_index = row_index.map(|(name, offset)| RowIndex {
             name: name.into(),
@@ -347,6 +350,7 @@ impl PyLazyFrame {
             cloud_options: None,
             hive_options,
             include_file_paths: include_file_paths.map(|x| x.into()),
+            unify_schemas,
         };
 
         let sources = sources.0;
@@ -616,7 +620,7 @@ impl PyLazyFrame {
     }
 
     #[cfg(all(feature = "streaming", feature = "parquet"))]
-    #[pyo3(signature = (path, compression, compression_level, statistics, row_group_size, data_page_size, maintain_order))]
+    #[pyo3(signature = (path, compression, compression_level, statistics, row_group_size, data_page_size, dictionary_encoding, column_compression, maintain_order))]
     fn sink_parquet(
         &self,
         py: Python,
@@ -626,9 +630,12 @@ impl PyLazyFrame {
         statistics: Wrap<StatisticsOptions>,
         row_group_size: Option<usize>,
         data_page_size: Option<usize>,
+        dictionary_encoding: bool,
+        column_compression: Option<Vec<(String, String)>>,
         maintain_order: bool,
     ) -> PyResult<()> {
         let compression = parse_parquet_compression(compression, compression_level)?;
+        let column_compression = parse_parquet_column_compression(column_compression)?;
 
         let options = ParquetWriteOptions {
             compression,
@@ -636,6 +643,8 @@ impl PyLazyFrame {
             row_group_size,
             data_page_size,
             maintain_order,
+            dictionary_encoding,
+            column_compression,
         };
 
         // if we don't allow threads and we have udfs trying to acquire the gil from different
@@ -900,6 +909,8 @@ impl PyLazyFrame {
         how: Wrap<JoinType>,
         suffix: String,
         validate: Wrap<JoinValidation>,
+        coerce_keys: bool,
+        algorithm: Wrap<JoinAlgorithm>,
         coalesce: Option<bool>,
     ) -> PyResult<Self> {
         let coalesce = match coalesce {
@@ -926,6 +937,8 @@ impl PyLazyFrame {
             .allow_parallel(allow_parallel)
             .force_parallel(force_parallel)
             .join_nulls(join_nulls)
+            .coerce_keys(coerce_keys)
+            .algorithm(algorithm.0)
             .how(how.0)
             .coalesce(coalesce)
             .validate(validate.0)
@@ -1288,6 +1288,12 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                     ("replace_strict",).to_object(py)
                 },
                 FunctionExpr::Negate => ("negate",).to_object(py),
+                FunctionExpr::ArithmeticWithOverflow { .. } => {
+                    return Err(PyNotImplementedError::new_err("arithmetic with overflow"))
+                },
+                FunctionExpr::SumWithOverflow(_) => {
+                    return Err(PyNotImplementedError::new_err("sum with overflow"))
+                },
                 FunctionExpr::FillNullWithStrategy(_) => {
                     return Err(PyNotImplementedError::new_err("fill null with strategy"))
                 },
@@ -107,13 +107,17 @@ impl PySeries {
 
     fn quantile(
         &self,
+        py: Python,
         quantile: f64,
         interpolation: Wrap<QuantileInterpolOptions>,
     ) -> PyResult<PyObject> {
-        let bind = self.series.quantile_reduce(quantile, interpolation.0);
-        let sc = bind.map_err(PyPolarsErr::from)?;
-
-        Ok(Python::with_gil(|py| Wrap(sc.as_any_value()).into_py(py)))
+        Ok(Wrap(
+            self.series
+                .quantile_reduce(quantile, interpolation.0)
+                .map_err(PyPolarsErr::from)?
+                .as_any_value(),
+        )
+        .into_py(py))
     }
 
     fn std(&self, py: Python, ddof: u8) -> PyResult<PyObject> {
@@ -3,6 +3,7 @@ use polars::prelude::*;
 use pyo3::ffi::Py_uintptr_t;
 use pyo3::prelude::*;
 
+use super::import::import_array_from_c;
 use super::PySeries;
 use crate::error::PyPolarsErr;
 
@@ -24,10 +25,9 @@ impl PySeries {
                 let array = std::ptr::read_unaligned(array_ptr);
                 let schema = &*schema_ptr;
 
-                let field = arrow::ffi::import_field_from_c(schema).unwrap();
-                arrow::ffi::import_array_from_c(array, field.dtype).unwrap()
+                import_array_from_c(schema, array).map(|(_, array)| array)
             })
-            .collect::<Vec<_>>();
+            .collect::<PyResult<Vec<_>>>()?;
 
         let s = Series::try_new(name.into(), chunks).map_err(PyPolarsErr::from)?;
         Ok(s.into())
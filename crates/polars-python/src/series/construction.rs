@@ -1,12 +1,12 @@
 use std::borrow::Cow;
 
+use num_traits::Float;
 use numpy::{Element, PyArray1, PyArrayMethods};
 use polars::export::arrow;
 use polars::export::arrow::array::Array;
-use polars::export::arrow::bitmap::MutableBitmap;
+use polars::export::arrow::bitmap::{Bitmap, MutableBitmap};
 use polars::export::arrow::types::NativeType;
 use polars_core::prelude::*;
-use polars_core::utils::CustomIterTools;
 use pyo3::exceptions::{PyTypeError, PyValueError};
 use pyo3::prelude::*;
 
@@ -57,6 +57,28 @@ fn mmap_numpy_array<T: Element + NativeType>(
         .into()
 }
 
+/// Copy a float array in one pass over the buffer, marking NaN values as null.
+fn numpy_float_array_nan_as_null<T>(
+    py: Python,
+    name: &str,
+    array: &Bound<PyArray1<T::Native>>,
+) -> PySeries
+where
+    T: PolarsFloatType,
+    T::Native: Element + Float,
+    ChunkedArray<T>: IntoSeries,
+{
+    let array = array.readonly();
+    let vals = array.as_slice().unwrap();
+    py.allow_threads(|| {
+        let validity: Bitmap = vals.iter().map(|v| !v.is_nan()).collect();
+        let validity = (validity.unset_bits() > 0).then_some(validity);
+        ChunkedArray::<T>::from_vec_validity(name.into(), vals.to_vec(), validity)
+            .into_series()
+            .into()
+    })
+}
+
 #[pymethods]
 impl PySeries {
     #[staticmethod]
@@ -69,13 +91,7 @@ impl PySeries {
     #[staticmethod]
     fn new_f32(py: Python, name: &str, array: &Bound<PyArray1<f32>>, nan_is_null: bool) -> Self {
         if nan_is_null {
-            let array = array.readonly();
-            let vals = array.as_slice().unwrap();
-            let ca: Float32Chunked = vals
-                .iter()
-                .map(|&val| if f32::is_nan(val) { None } else { Some(val) })
-                .collect_trusted();
-            ca.with_name(name.into()).into_series().into()
+            numpy_float_array_nan_as_null::<Float32Type>(py, name, array)
         } else {
             mmap_numpy_array(py, name, array)
         }
@@ -84,13 +100,7 @@ impl PySeries {
     #[staticmethod]
     fn new_f64(py: Python, name: &str, array: &Bound<PyArray1<f64>>, nan_is_null: bool) -> Self {
         if nan_is_null {
-            let array = array.readonly();
-            let vals = array.as_slice().unwrap();
-            let ca: Float64Chunked = vals
-                .iter()
-                .map(|&val| if f64::is_nan(val) { None } else { Some(val) })
-                .collect_trusted();
-            ca.with_name(name.into()).into_series().into()
+            numpy_float_array_nan_as_null::<Float64Type>(py, name, array)
         } else {
             mmap_numpy_array(py, name, array)
         }
@@ -186,6 +196,7 @@ impl PySeries {
         });
 
         // Fall back to Object type for non-strict construction.
+        #[cfg(feature = "object")]
         if !strict && result.is_err() {
             return Python::with_gil(|py| {
                 let objects = values
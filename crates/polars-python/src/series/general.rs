@@ -387,7 +387,7 @@ impl PySeries {
         IpcStreamWriter::new(&mut buf)
             .with_compat_level(CompatLevel::newest())
             .finish(&mut df)
-            .expect("ipc writer");
+            .map_err(PyPolarsErr::from)?;
         Ok(PyBytes::new_bound(py, &buf).to_object(py))
     }
 
@@ -576,7 +576,7 @@ impl_get!(get_duration, duration, i64);
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::series::ToSeries;
+    use crate::series::{ToPySeries, ToSeries};
 
     #[test]
     fn transmute_to_series() {
@@ -597,5 +597,9 @@ mod test {
                 .collect::<Vec<_>>(),
             vec![6]
         );
+
+        let ps = s.to_pyseries();
+        assert_eq!(ps.len(), 1);
+        assert_eq!(ps[0].series.sum::<i32>().unwrap(), 6);
     }
 }
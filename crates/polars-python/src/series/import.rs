@@ -10,6 +10,7 @@ use pyo3::prelude::*;
 use pyo3::types::{PyCapsule, PyTuple, PyType};
 
 use super::PySeries;
+use crate::error::PyPolarsErr;
 
 /// Validate PyCapsule has provided name
 fn validate_pycapsule_name(capsule: &Bound<PyCapsule>, expected_name: &str) -> PyResult<()> {
@@ -53,6 +54,20 @@ pub(crate) fn call_arrow_c_array<'py>(
     Ok((schema_capsule, array_capsule))
 }
 
+/// Import a C schema and array, raising instead of panicking on invalid data.
+///
+/// # Safety
+/// `schema` and `array` must be valid structs of the Arrow C Data Interface.
+pub(crate) unsafe fn import_array_from_c(
+    schema: &ArrowSchema,
+    array: ArrowArray,
+) -> PyResult<(arrow::datatypes::Field, Box<dyn Array>)> {
+    let field = ffi::import_field_from_c(schema).map_err(PyPolarsErr::from)?;
+    let array =
+        ffi::import_array_from_c(array, field.dtype().clone()).map_err(PyPolarsErr::from)?;
+    Ok((field, array))
+}
+
 pub(crate) fn import_array_pycapsules(
     schema_capsule: &Bound<PyCapsule>,
     array_capsule: &Bound<PyCapsule>,
@@ -65,16 +80,11 @@ pub(crate) fn import_array_pycapsules(
     // Interface
     // array_capsule holds a valid C ArrowArray pointer, as defined by the Arrow PyCapsule
     // Interface
-    let (field, array) = unsafe {
+    unsafe {
         let schema_ptr = schema_capsule.reference::<ArrowSchema>();
         let array_ptr = std::ptr::replace(array_capsule.pointer() as _, ArrowArray::empty());
-
-        let field = ffi::import_field_from_c(schema_ptr).unwrap();
-        let array = ffi::import_array_from_c(array_ptr, field.dtype().clone()).unwrap();
-        (field, array)
-    };
-
-    Ok((field, array))
+        import_array_from_c(schema_ptr, array_ptr)
+    }
 }
 
 /// Import `__arrow_c_stream__` across Python boundary.
@@ -108,7 +118,7 @@ pub(crate) fn import_stream_pycapsule(capsule: &Bound<PyCapsule>) -> PyResult<Py
 
     let mut produced_arrays: Vec<Box<dyn Array>> = vec![];
     while let Some(array) = unsafe { stream.next() } {
-        produced_arrays.push(array.unwrap());
+        produced_arrays.push(array.map_err(PyPolarsErr::from)?);
     }
 
     // Series::try_from fails for an empty vec of chunks
@@ -116,7 +126,7 @@ pub(crate) fn import_stream_pycapsule(capsule: &Bound<PyCapsule>) -> PyResult<Py
         let polars_dt = DataType::from_arrow(stream.field().dtype(), false);
         Series::new_empty(stream.field().name.clone(), &polars_dt)
     } else {
-        Series::try_from((stream.field(), produced_arrays)).unwrap()
+        Series::try_from((stream.field(), produced_arrays)).map_err(PyPolarsErr::from)?
     };
     Ok(PySeries::new(s))
 }
@@ -126,7 +136,7 @@ impl PySeries {
     pub fn from_arrow_c_array(_cls: &Bound<PyType>, ob: &Bound<'_, PyAny>) -> PyResult<Self> {
         let (schema_capsule, array_capsule) = call_arrow_c_array(ob)?;
         let (field, array) = import_array_pycapsules(&schema_capsule, &array_capsule)?;
-        let s = Series::try_from((&field, array)).unwrap();
+        let s = Series::try_from((&field, array)).map_err(PyPolarsErr::from)?;
         Ok(PySeries::new(s))
     }
 
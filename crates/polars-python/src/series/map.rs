@@ -2,8 +2,10 @@ use pyo3::prelude::*;
 use pyo3::types::PyCFunction;
 use pyo3::Python;
 
+use super::import::{call_arrow_c_array, import_array_pycapsules};
 use super::PySeries;
 use crate::error::PyPolarsErr;
+use crate::interop::arrow::to_py::PyArrowArray;
 use crate::map::series::{call_lambda_and_extract, ApplyLambda};
 use crate::prelude::*;
 use crate::py_modules::SERIES;
@@ -11,6 +13,30 @@ use crate::{apply_method_all_arrow_series2, raise_err};
 
 #[pymethods]
 impl PySeries {
+    /// Call `function` with every chunk exported as an Arrow array, and build a Series from
+    /// the Arrow arrays it returns.
+    fn map_chunks(&self, function: &Bound<PyAny>) -> PyResult<Self> {
+        let field = self.series.field().to_arrow(CompatLevel::newest());
+        let mut out_field = None;
+        let chunks = (0..self.series.n_chunks())
+            .map(|i| {
+                let array = self.series.to_arrow(i, CompatLevel::newest());
+                let out = function.call1((PyArrowArray::new(field.clone(), array),))?;
+                let (schema_capsule, array_capsule) = call_arrow_c_array(&out)?;
+                let (field, array) = import_array_pycapsules(&schema_capsule, &array_capsule)?;
+                out_field = Some(field);
+                Ok(array)
+            })
+            .collect::<PyResult<Vec<_>>>()?;
+
+        let Some(mut out_field) = out_field else {
+            return Ok(self.clone());
+        };
+        out_field.name = self.series.name().clone();
+        let s = Series::try_from((&out_field, chunks)).map_err(PyPolarsErr::from)?;
+        Ok(s.into())
+    }
+
     #[pyo3(signature = (function, return_dtype, skip_nulls))]
     fn map_elements(
         &self,
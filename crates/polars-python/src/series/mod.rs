@@ -45,14 +45,20 @@ impl PySeries {
     }
 }
 
+// `PySeries` is a transparent wrapper, so the conversions below collect in place
+// without reallocating.
+const _: () = assert!(
+    std::mem::size_of::<PySeries>() == std::mem::size_of::<Series>()
+        && std::mem::align_of::<PySeries>() == std::mem::align_of::<Series>()
+);
+
 pub(crate) trait ToSeries {
     fn to_series(self) -> Vec<Series>;
 }
 
 impl ToSeries for Vec<PySeries> {
     fn to_series(self) -> Vec<Series> {
-        // SAFETY: repr is transparent.
-        unsafe { std::mem::transmute(self) }
+        self.into_iter().map(|s| s.series).collect()
     }
 }
 
@@ -62,7 +68,6 @@ pub(crate) trait ToPySeries {
 
 impl ToPySeries for Vec<Series> {
     fn to_pyseries(self) -> Vec<PySeries> {
-        // SAFETY: repr is transparent.
-        unsafe { std::mem::transmute(self) }
+        self.into_iter().map(PySeries::from).collect()
     }
 }
@@ -108,7 +108,9 @@ macro_rules! impl_ufuncs {
                             // args and the lambda return have a reference, making a total of 3
                             assert!(get_refcnt(&out_array) <= 3);
 
-                            let validity = self.series.chunks()[0].validity().cloned();
+                            // The output buffer is contiguous, so the validity must be
+                            // taken from a single chunk as well.
+                            let validity = self.series.rechunk().chunks()[0].validity().cloned();
                             let ca = ChunkedArray::<$type>::from_vec_validity(
                                 self.series.name().clone(),
                                 av,
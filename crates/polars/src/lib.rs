@@ -395,6 +395,7 @@
 //! * `POLARS_FMT_STR_LEN` -> maximum number of characters printed per string value.
 //! * `POLARS_TABLE_WIDTH` -> width of the tables used during DataFrame formatting.
 //! * `POLARS_MAX_THREADS` -> maximum number of threads used to initialize thread pool (on startup).
+//!                           [`set_num_threads`] takes precedence if called before the pool is used.
 //! * `POLARS_VERBOSE` -> print logging info to stderr.
 //! * `POLARS_NO_PARTITION` -> polars may choose to partition the group_by operation, based on data
 //!                            cardinality. Setting this env var will turn partitioned group_by's off.
@@ -407,6 +408,12 @@
 //! * `POLARS_PANIC_ON_ERR` -> panic instead of returning an Error.
 //! * `POLARS_BACKTRACE_IN_ERR` -> include a Rust backtrace in Error messages.
 //! * `POLARS_NO_CHUNKED_JOIN` -> force rechunk before joins.
+//! * `POLARS_NO_SORT_MERGE_JOIN` -> always use a hash join, also if the join keys are sorted.
+//! * `POLARS_JOIN_SORT_FACTOR` -> if only one join key is sorted, the other key is sorted to use a sorted
+//!                                merge join if it is shorter than this factor times the sorted key.
+//!                                Defaults to `1.0`.
+//! * `POLARS_BROADCAST_JOIN_THRESHOLD` -> the maximum number of rows of the build side for which a
+//!                                        broadcast join is used instead of a hash join. Defaults to `8`.
 //!
 //! ## User guide
 //!
@@ -424,6 +431,9 @@ pub use polars_core::{
     apply_method_all_arrow_series, chunked_array, datatypes, df, error, frame, functions, series,
     testing,
 };
+#[cfg(not(target_family = "wasm"))]
+pub use polars_core::set_num_threads;
+pub use polars_core::thread_pool_size;
 #[cfg(feature = "dtype-categorical")]
 pub use polars_core::{enable_string_cache, using_string_cache};
 #[cfg(feature = "polars-io")]
@@ -0,0 +1,140 @@
+use polars::prelude::array::ArrayNameSpace;
+use polars::prelude::*;
+
+fn array_series(name: &str, values: &[i64], width: i64) -> PolarsResult<Series> {
+    Series::new(name.into(), values).reshape_array(&[-1, width])
+}
+
+#[test]
+fn test_array_arithmetic() -> PolarsResult<()> {
+    let a = array_series("a", &[1, 2, 3, 4, 5, 6], 3)?;
+    let b = array_series("b", &[10, 20, 30, 40, 50, 60], 3)?;
+
+    let out = (&a + &b)?;
+    assert_eq!(out.dtype(), &DataType::Array(Box::new(DataType::Int64), 3));
+    assert!(out.equals(&array_series("a", &[11, 22, 33, 44, 55, 66], 3)?));
+
+    let out = (&b - &a)?;
+    assert!(out.equals(&array_series("b", &[9, 18, 27, 36, 45, 54], 3)?));
+
+    // Scalars are broadcast to every element.
+    let out = (&a * &Series::new("".into(), [2i64]))?;
+    assert!(out.equals(&array_series("a", &[2, 4, 6, 8, 10, 12], 3)?));
+
+    // Arrays of a different width can't be combined.
+    let c = array_series("c", &[1, 2, 3, 4, 5, 6], 2)?;
+    assert!((&a + &c).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_array_arithmetic_nested() -> PolarsResult<()> {
+    let a = Series::new("a".into(), (0i64..12).collect::<Vec<_>>()).reshape_array(&[-1, 3, 2])?;
+    let dtype = DataType::Array(Box::new(DataType::Array(Box::new(DataType::Int64), 2)), 3);
+    assert_eq!(a.dtype(), &dtype);
+
+    let out = (&a + &a)?;
+    assert_eq!(out.dtype(), &dtype);
+    assert_eq!(out.len(), 2);
+    assert!(out.equals(
+        &Series::new("a".into(), (0i64..12).map(|v| v * 2).collect::<Vec<_>>())
+            .reshape_array(&[-1, 3, 2])?
+    ));
+    Ok(())
+}
+
+#[test]
+fn test_array_arithmetic_nulls() -> PolarsResult<()> {
+    let a = array_series("a", &[1, 2, 3, 4, 5, 6], 2)?;
+    let mask = BooleanChunked::new("".into(), [true, false, true]);
+    let with_null = a.zip_with(&mask, &Series::full_null("".into(), 3, a.dtype()))?;
+    assert_eq!(with_null.null_count(), 1);
+
+    let out = (&with_null + &a)?;
+    assert_eq!(out.len(), 3);
+    assert_eq!(out.null_count(), 1);
+    assert!(out.get(1)?.is_null());
+
+    let out = (&a + &with_null)?;
+    assert_eq!(out.null_count(), 1);
+
+    let out = (&with_null * &Series::new("".into(), [2i64]))?;
+    assert_eq!(out.null_count(), 1);
+    Ok(())
+}
+
+#[test]
+fn test_array_arithmetic_broadcast() -> PolarsResult<()> {
+    let a = array_series("a", &[1, 2, 3, 4, 5, 6], 2)?;
+    let unit = array_series("unit", &[10, 20], 2)?;
+
+    let out = (&unit + &a)?;
+    assert!(out.equals(&array_series("unit", &[11, 22, 13, 24, 15, 26], 2)?));
+    let out = (&a - &unit)?;
+    assert!(out.equals(&array_series("a", &[-9, -18, -7, -16, -5, -14], 2)?));
+
+    // A null unit length array makes every row null.
+    let null_unit = Series::full_null("".into(), 1, a.dtype());
+    let out = (&a + &null_unit)?;
+    assert_eq!(out.len(), 3);
+    assert_eq!(out.null_count(), 3);
+    let out = (&null_unit + &a)?;
+    assert_eq!(out.len(), 3);
+    assert_eq!(out.null_count(), 3);
+
+    // Other length mismatches can't be broadcast.
+    let b = array_series("b", &[1, 2, 3, 4], 2)?;
+    assert!((&a + &b).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_array_get_sum_and_list_casts() -> PolarsResult<()> {
+    let a = array_series("a", &[1, 2, 3, 4, 5, 6], 3)?;
+    let ca = a.array()?;
+
+    assert_eq!(Vec::from(ca.array_sum()?.i64()?), &[Some(6), Some(15)]);
+    let first = ca.array_get(&Int64Chunked::from_slice("".into(), &[0]), false)?;
+    assert_eq!(Vec::from(first.i64()?), &[Some(1), Some(4)]);
+    let last = ca.array_get(&Int64Chunked::from_slice("".into(), &[-1]), false)?;
+    assert_eq!(Vec::from(last.i64()?), &[Some(3), Some(6)]);
+    assert!(ca
+        .array_get(&Int64Chunked::from_slice("".into(), &[3]), false)
+        .is_err());
+
+    let list = a.cast(&DataType::List(Box::new(DataType::Int64)))?;
+    assert_eq!(list.len(), 2);
+    let back = list.cast(a.dtype())?;
+    assert!(back.equals(&a));
+
+    // Lists that don't match the width don't fit the array.
+    let ragged = Series::new(
+        "a".into(),
+        [
+            Series::new("".into(), [1i64, 2]),
+            Series::new("".into(), [3i64]),
+        ],
+    );
+    assert!(ragged.cast(a.dtype()).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_array_arithmetic_lazy() -> PolarsResult<()> {
+    let df = DataFrame::new(vec![array_series("a", &[1, 2, 3, 4], 2)?])?;
+    let out = df
+        .lazy()
+        .select([
+            (col("a") + col("a")).alias("sum"),
+            (col("a") * lit(3)).alias("scaled"),
+        ])
+        .collect()?;
+
+    assert!(out
+        .column("sum")?
+        .equals(&array_series("sum", &[2, 4, 6, 8], 2)?));
+    assert!(out
+        .column("scaled")?
+        .equals(&array_series("scaled", &[3, 6, 9, 12], 2)?));
+    Ok(())
+}
@@ -634,3 +634,118 @@ fn test_4_threads_bit_offset() -> PolarsResult<()> {
     assert_eq!(out.shape(), (1, 2));
     Ok(())
 }
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn test_sorted_merge_join_matches_hash_join() -> PolarsResult<()> {
+    use polars_core::series::IsSorted;
+
+    let left = df![
+        "a" => [0i64, 1, 2, 2, 3, 5],
+        "l" => [0, 1, 2, 3, 4, 5]
+    ]?;
+    let right = df![
+        "a" => [2i64, 5, 1, 2, 7],
+        "r" => [0, 1, 2, 3, 4]
+    ]?;
+
+    let set_sorted = |s: &Series| {
+        let mut s = s.clone();
+        s.set_sorted_flag(IsSorted::Ascending);
+        s
+    };
+    let mut left_sorted = left.clone();
+    left_sorted.apply("a", set_sorted)?;
+    let mut right_sorted = right.sort(["a"], Default::default())?;
+    right_sorted.apply("a", set_sorted)?;
+
+    for how in [JoinType::Inner, JoinType::Left] {
+        let sort = |df: DataFrame| df.sort(["l", "r"], Default::default());
+        let expected = sort(left.join(&right, ["a"], ["a"], how.clone().into())?)?;
+
+        // Both keys sorted, and only the left key sorted.
+        for right in [&right_sorted, &right] {
+            let out = sort(left_sorted.join(right, ["a"], ["a"], how.clone().into())?)?;
+            assert!(out.equals_missing(&expected), "{how:?}");
+        }
+    }
+    Ok(())
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn test_join_algorithms_match_hash_join() -> PolarsResult<()> {
+    let left = df![
+        "a" => [Some(3i64), Some(1), None, Some(2), Some(5), Some(2)],
+        "l" => [0, 1, 2, 3, 4, 5]
+    ]?;
+    let right = df![
+        "a" => [Some(2i64), None, Some(5), Some(7)],
+        "r" => [0, 1, 2, 3]
+    ]?;
+
+    for how in [JoinType::Inner, JoinType::Left, JoinType::Right] {
+        for join_nulls in [false, true] {
+            let join = |left: &DataFrame, right: &DataFrame, algorithm| {
+                let mut args = JoinArgs::new(how.clone()).with_algorithm(algorithm);
+                args.join_nulls = join_nulls;
+                left.join(right, ["a"], ["a"], args)?
+                    .sort(["l", "r"], Default::default())
+            };
+
+            // The right side is tiny, so `Auto` broadcasts it as well.
+            for (left, right) in [(&left, &right), (&right, &left)] {
+                let expected = join(left, right, JoinAlgorithm::Hash)?;
+                for algorithm in [JoinAlgorithm::Auto, JoinAlgorithm::Broadcast] {
+                    let out = join(left, right, algorithm)?;
+                    assert!(out.equals_missing(&expected), "{how:?} {algorithm:?}");
+                }
+            }
+
+            #[cfg(feature = "performant")]
+            {
+                let left = left.drop_nulls::<String>(None)?;
+                let right = right.drop_nulls::<String>(None)?;
+                let expected = join(&left, &right, JoinAlgorithm::Hash)?;
+                let out = join(&left, &right, JoinAlgorithm::SortMerge)?;
+                assert!(out.equals_missing(&expected), "{how:?}");
+            }
+        }
+    }
+
+    // A sort-merge join can't join on nulls, and validation requires a hash join.
+    let args = JoinArgs::new(JoinType::Inner).with_algorithm(JoinAlgorithm::SortMerge);
+    assert!(left.join(&right, ["a"], ["a"], args).is_err());
+    let mut args = JoinArgs::new(JoinType::Inner).with_algorithm(JoinAlgorithm::Broadcast);
+    args.validation = JoinValidation::OneToOne;
+    assert!(left.join(&right, ["a"], ["a"], args).is_err());
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "performant")]
+#[cfg_attr(miri, ignore)]
+fn test_sort_merge_left_join_keeps_left_order() -> PolarsResult<()> {
+    let left = df![
+        "a" => [3i64, 1, 2, 5, 2],
+        "l" => [0, 1, 2, 3, 4]
+    ]?;
+    let right = df![
+        "a" => [2i64, 5, 2, 7],
+        "r" => [0, 1, 2, 3]
+    ]?;
+
+    let args = JoinArgs::new(JoinType::Left).with_algorithm(JoinAlgorithm::SortMerge);
+    let out = left.join(&right, ["a"], ["a"], args)?;
+    let l = out
+        .column("l")?
+        .i32()?
+        .into_no_null_iter()
+        .collect::<Vec<_>>();
+    assert_eq!(l, &[0, 1, 2, 2, 3, 4, 4]);
+    assert_eq!(
+        Vec::from(out.column("r")?.i32()?),
+        &[None, None, Some(0), Some(2), Some(1), Some(0), Some(2)]
+    );
+    Ok(())
+}
@@ -14,3 +14,36 @@ fn test_to_list_logical() -> PolarsResult<()> {
     assert_eq!(expl.dtype(), &DataType::Date);
     Ok(())
 }
+
+#[test]
+fn test_list_filter_take_get() -> PolarsResult<()> {
+    let s = Series::new(
+        "a".into(),
+        [
+            Series::new("".into(), [1i32, 2]),
+            Series::new("".into(), [3i32]),
+            Series::new("".into(), Vec::<i32>::new()),
+        ],
+    );
+    assert_eq!(s.dtype(), &DataType::List(Box::new(DataType::Int32)));
+
+    let mask = BooleanChunked::new("".into(), [true, false, true]);
+    let filtered = s.filter(&mask)?;
+    assert_eq!(filtered.len(), 2);
+    assert_eq!(filtered.dtype(), s.dtype());
+
+    let taken = s.take_slice(&[1, 0])?;
+    match taken.get(0)? {
+        AnyValue::List(inner) => assert_eq!(Vec::from(inner.i32()?), &[Some(3)]),
+        av => panic!("expected a list, got {av:?}"),
+    }
+    match taken.get(1)? {
+        AnyValue::List(inner) => assert_eq!(Vec::from(inner.i32()?), &[Some(1), Some(2)]),
+        av => panic!("expected a list, got {av:?}"),
+    }
+
+    let expl = s.explode()?;
+    assert_eq!(expl.len(), 4);
+    assert_eq!(expl.null_count(), 1);
+    Ok(())
+}
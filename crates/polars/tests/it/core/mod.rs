@@ -1,3 +1,5 @@
+#[cfg(feature = "dtype-array")]
+mod array;
 mod date_like;
 mod group_by;
 mod joins;
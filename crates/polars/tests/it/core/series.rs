@@ -41,3 +41,73 @@ fn test_construct_list_of_null_series() {
     assert_eq!(s.null_count(), 0);
     assert_eq!(s.field().name(), "a");
 }
+
+#[test]
+fn test_construct_with_nulls() -> PolarsResult<()> {
+    let s = Series::new("a".into(), &[Some(1i64), None, Some(3)]);
+    assert_eq!(s.dtype(), &DataType::Int64);
+    assert_eq!(s.null_count(), 1);
+    assert_eq!(Vec::from(s.i64()?), &[Some(1), None, Some(3)]);
+
+    let s = Series::new("b".into(), &[None, Some(true)]);
+    assert_eq!(s.dtype(), &DataType::Boolean);
+    assert_eq!(s.null_count(), 1);
+
+    let s = Series::new("c".into(), &[Some("x"), None]);
+    assert_eq!(s.dtype(), &DataType::String);
+    assert_eq!(Vec::from(s.str()?), &[Some("x"), None]);
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "dtype-u8", feature = "dtype-i8", feature = "dtype-u16"))]
+fn test_small_integer_widths() -> PolarsResult<()> {
+    let a = Series::new("a".into(), &[1u8, 2, 200]);
+    let b = Series::new("b".into(), &[3u8, 4, 5]);
+    assert_eq!(a.dtype(), &DataType::UInt8);
+
+    // Arithmetic keeps the narrow width.
+    let sum = (&a + &b)?;
+    assert_eq!(sum.dtype(), &DataType::UInt8);
+    assert_eq!(Vec::from(sum.u8()?), &[Some(4), Some(6), Some(205)]);
+
+    let mask = a.gt(1)?;
+    assert_eq!(Vec::from(&mask), &[Some(false), Some(true), Some(true)]);
+
+    // Out-of-range values become null in a non-strict cast and error in a strict one.
+    let cast = a.cast(&DataType::Int8)?;
+    assert_eq!(Vec::from(cast.i8()?), &[Some(1), Some(2), None]);
+    assert!(a.strict_cast(&DataType::Int8).is_err());
+    let cast = a.cast(&DataType::UInt16)?;
+    assert_eq!(Vec::from(cast.u16()?), &[Some(1), Some(2), Some(200)]);
+
+    // Sums are computed in Int64 to prevent overflow.
+    let total = a.sum_reduce()?;
+    assert_eq!(total.dtype(), &DataType::Int64);
+    assert_eq!(total.value(), &AnyValue::Int64(203));
+    assert_eq!(a.max::<u8>()?, Some(200));
+    Ok(())
+}
+
+#[test]
+fn test_null_dtype_promotion() -> PolarsResult<()> {
+    let nulls = Series::new_null("a".into(), 2);
+    assert_eq!(nulls.dtype(), &DataType::Null);
+    assert_eq!(nulls.null_count(), 2);
+
+    assert_eq!(
+        polars_core::utils::get_supertype(&DataType::Null, &DataType::Int64),
+        Some(DataType::Int64)
+    );
+
+    let mut s = Series::new("a".into(), &[1i64, 2]);
+    s.append(&nulls)?;
+    assert_eq!(s.dtype(), &DataType::Int64);
+    assert_eq!(Vec::from(s.i64()?), &[Some(1), Some(2), None, None]);
+
+    // A null column cannot absorb typed values without an explicit cast.
+    let mut nulls = nulls;
+    assert!(nulls.append(&s).is_err());
+    assert!(nulls.extend(&s).is_err());
+    Ok(())
+}
@@ -39,6 +39,37 @@ fn write_csv() {
     assert_eq!("0,22.1\r\n1,19.9\r\n2,7.0\r\n3,2.0\r\n4,3.0\r\n", csv);
 }
 
+#[test]
+fn write_csv_separator_and_batched() -> PolarsResult<()> {
+    let mut df = df![
+        "a" => [Some(1), None],
+        "b" => ["x;y", "z"],
+    ]?;
+
+    // Fields containing the separator are quoted.
+    let mut buf: Vec<u8> = Vec::new();
+    CsvWriter::new(&mut buf)
+        .with_separator(b';')
+        .with_null_value("NA".into())
+        .finish(&mut df)?;
+    assert_eq!("a;b\n1;\"x;y\"\nNA;z\n", std::str::from_utf8(&buf).unwrap());
+
+    // The header is written once, before the first batch.
+    let mut buf: Vec<u8> = Vec::new();
+    let mut writer = CsvWriter::new(&mut buf).batched(&df.schema())?;
+    writer.write_batch(&df.slice(0, 1))?;
+    writer.write_batch(&df.slice(1, 1))?;
+    writer.finish()?;
+    drop(writer);
+    assert_eq!("a,b\n1,x;y\n,z\n", std::str::from_utf8(&buf).unwrap());
+
+    // Without batches only the header is written.
+    let mut buf: Vec<u8> = Vec::new();
+    CsvWriter::new(&mut buf).finish(&mut df.clear())?;
+    assert_eq!("a,b\n", std::str::from_utf8(&buf).unwrap());
+    Ok(())
+}
+
 #[test]
 #[cfg(feature = "timezones")]
 fn write_dates() {
@@ -1396,3 +1427,53 @@ fn test_read_io_reader() {
     let expected = CsvReader::new(file).finish().unwrap();
     assert!(df.equals(&expected))
 }
+
+#[test]
+fn test_write_read_nulls_and_special_floats() -> PolarsResult<()> {
+    let mut df = df![
+        "f64" => [Some(1.5f64), None, Some(f64::NAN), Some(f64::INFINITY), Some(f64::NEG_INFINITY)],
+        "f32" => [Some(-0.5f32), Some(f32::NAN), None, Some(f32::NEG_INFINITY), Some(2.0)],
+        "i64" => [Some(1i64), None, Some(3), Some(4), None],
+        "str" => [Some("a"), None, Some("NaN"), Some(""), Some("inf")]
+    ]?;
+
+    for null in ["", "NA", "null"] {
+        let mut buf = Vec::new();
+        CsvWriter::new(&mut buf)
+            .with_null_value(null.into())
+            .finish(&mut df)?;
+
+        let null_values = Some(NullValues::AllColumnsSingle(null.into()));
+        let out = CsvReadOptions::default()
+            .with_parse_options(CsvParseOptions::default().with_null_values(null_values))
+            .with_schema_overwrite(Some(Arc::new(df.schema())))
+            .into_reader_with_file_handle(Cursor::new(buf))
+            .finish()?;
+
+        // An empty string can't be distinguished from a null if nulls are written as "".
+        if null.is_empty() {
+            assert!(out.drop("str")?.equals_missing(&df.drop("str")?));
+        } else {
+            assert!(out.equals_missing(&df));
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn test_infer_special_floats() -> PolarsResult<()> {
+    let csv = "a,b\nnan,1.0\n+inf,NaN\n-Infinity,INF\n";
+    let df = CsvReadOptions::default()
+        .into_reader_with_file_handle(Cursor::new(csv))
+        .finish()?;
+
+    assert_eq!(df.dtypes(), &[DataType::Float64, DataType::Float64]);
+    let a = df.column("a")?.f64()?;
+    assert!(a.get(0).unwrap().is_nan());
+    assert_eq!(a.get(1), Some(f64::INFINITY));
+    assert_eq!(a.get(2), Some(f64::NEG_INFINITY));
+    let b = df.column("b")?.f64()?;
+    assert!(b.get(1).unwrap().is_nan());
+    assert_eq!(b.get(2), Some(f64::INFINITY));
+    Ok(())
+}
@@ -1,5 +1,6 @@
 use std::io::{Cursor, Seek, SeekFrom};
 
+use polars::io::RowIndex;
 use polars::prelude::*;
 
 #[test]
@@ -62,7 +63,7 @@ fn test_read_ipc_with_projection() {
         .finish()
         .unwrap();
     assert_eq!(df_read.shape(), (3, 2));
-    df_read.equals(&expected);
+    assert!(df_read.equals(&expected));
 }
 
 #[test]
@@ -75,12 +76,13 @@ fn test_read_ipc_with_columns() {
         .expect("ipc writer");
     buf.set_position(0);
 
-    let expected = df!("b" => [2, 3, 4], "c" => [3, 4, 5]).unwrap();
+    // Columns are returned in the requested order.
+    let expected = df!("c" => [3, 4, 5], "b" => [2, 3, 4]).unwrap();
     let df_read = IpcReader::new(buf)
         .with_columns(Some(vec!["c".to_string(), "b".to_string()]))
         .finish()
         .unwrap();
-    df_read.equals(&expected);
+    assert!(df_read.equals(&expected));
 
     for compat_level in [0, 1].map(|level| CompatLevel::with_level(level).unwrap()) {
         let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
@@ -151,3 +153,61 @@ fn write_and_read_ipc_empty_series() {
     let df_read = IpcReader::new(buf).finish().unwrap();
     assert!(df.equals(&df_read));
 }
+
+#[test]
+fn test_read_ipc_with_n_rows_and_row_index() {
+    let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+    let mut df = create_df();
+    IpcWriter::new(&mut buf)
+        .finish(&mut df)
+        .expect("ipc writer");
+    buf.set_position(0);
+
+    let df_read = IpcReader::new(buf)
+        .with_n_rows(Some(3))
+        .with_row_index(Some(RowIndex {
+            name: "index".into(),
+            offset: 10,
+        }))
+        .finish()
+        .unwrap();
+    assert_eq!(df_read.get_column_names(), &["index", "days", "temp"]);
+    assert_eq!(
+        Vec::from(df_read.column("index").unwrap().idx().unwrap()),
+        &[Some(10), Some(11), Some(12)]
+    );
+    assert!(df_read.drop("index").unwrap().equals(&df.slice(0, 3)));
+}
+
+#[test]
+#[cfg(all(feature = "dtype-categorical", feature = "dtype-datetime"))]
+fn write_and_read_ipc_logical_and_nested() {
+    let dates = Int64Chunked::new("dt".into(), &[Some(0), None, Some(86_400_000)])
+        .into_datetime(TimeUnit::Milliseconds, Some("UTC".into()))
+        .into_series();
+    let cats = Series::new("cat".into(), &[Some("a"), Some("b"), None])
+        .cast(&DataType::Categorical(None, Default::default()))
+        .unwrap();
+    let lists = Series::new(
+        "list".into(),
+        [
+            Series::new("".into(), [1i32, 2]),
+            Series::new("".into(), Vec::<i32>::new()),
+            Series::new("".into(), [3i32]),
+        ],
+    );
+    let mut df = DataFrame::new(vec![dates, cats, lists]).unwrap();
+
+    for compat_level in [0, 1].map(|level| CompatLevel::with_level(level).unwrap()) {
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        IpcWriter::new(&mut buf)
+            .with_compat_level(compat_level)
+            .finish(&mut df)
+            .expect("ipc writer");
+        buf.set_position(0);
+
+        let df_read = IpcReader::new(buf).finish().unwrap();
+        assert_eq!(df_read.schema(), df.schema());
+        assert!(df_read.equals_missing(&df));
+    }
+}
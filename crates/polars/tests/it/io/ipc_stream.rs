@@ -5,7 +5,7 @@ mod test {
     use polars_core::prelude::*;
     use polars_core::{assert_df_eq, df};
     use polars_io::ipc::*;
-    use polars_io::{SerReader, SerWriter};
+    use polars_io::{RowIndex, SerReader, SerWriter};
 
     use crate::io::create_df;
 
@@ -156,4 +156,30 @@ mod test {
         let actual = IpcStreamReader::new(reader).finish().unwrap();
         assert_df_eq!(df(), actual);
     }
+
+    #[test]
+    fn test_read_ipc_stream_with_n_rows_and_row_index() {
+        let mut df = df!("a" => [1, 2, 3], "b" => ["x", "y", "z"]).unwrap();
+        // Write several record batches to check that `n_rows` stops across batches.
+        df.vstack_mut(&df.clone()).unwrap();
+        assert_eq!(df.n_chunks(), 2);
+
+        let reader = create_ipc_stream(df);
+        let actual = IpcStreamReader::new(reader)
+            .with_n_rows(Some(4))
+            .with_row_index(Some(RowIndex {
+                name: "idx".into(),
+                offset: 10,
+            }))
+            .finish()
+            .unwrap();
+
+        let expected = df!(
+            "idx" => [10 as IdxSize, 11, 12, 13],
+            "a" => [1, 2, 3, 1],
+            "b" => ["x", "y", "z", "x"],
+        )
+        .unwrap();
+        assert_df_eq!(actual, expected);
+    }
 }
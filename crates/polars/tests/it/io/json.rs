@@ -185,3 +185,108 @@ fn test_read_ndjson_iss_6148() {
     let df = JsonLineReader::new(cursor).finish();
     assert!(df.is_ok());
 }
+
+#[test]
+fn test_json_roundtrip_both_formats() -> PolarsResult<()> {
+    let mut df = df![
+        "a" => [Some(1i64), None, Some(3)],
+        "b" => [Some("x"), Some("y"), None],
+        "c" => [1.5, 2.0, -0.5],
+        "d" => [true, false, true],
+    ]?;
+
+    for format in [JsonFormat::Json, JsonFormat::JsonLines] {
+        let mut buf = Vec::new();
+        JsonWriter::new(&mut buf)
+            .with_json_format(format)
+            .finish(&mut df)?;
+
+        let out = JsonReader::new(Cursor::new(buf))
+            .with_json_format(format)
+            .finish()?;
+        assert_eq!(out.schema(), df.schema());
+        assert!(out.equals_missing(&df), "{format:?}: {out:?}");
+    }
+    Ok(())
+}
+
+#[test]
+fn test_read_json_array_of_records() -> PolarsResult<()> {
+    let json = r#"[{"a": 1, "b": "x"}, {"a": null, "c": 2.5}, {"b": "z"}]"#;
+    let df = JsonReader::new(Cursor::new(json)).finish()?;
+
+    let mut expected = Schema::default();
+    expected.with_column("a".into(), DataType::Int64);
+    expected.with_column("b".into(), DataType::String);
+    expected.with_column("c".into(), DataType::Float64);
+    assert_eq!(df.schema(), expected);
+    assert_eq!(df.height(), 3);
+    assert_eq!(
+        Vec::from(df.column("b")?.str()?),
+        &[Some("x"), None, Some("z")]
+    );
+
+    let df = JsonReader::new(Cursor::new(json))
+        .with_projection(Some(vec!["c".into()]))
+        .finish()?;
+    assert_eq!(df.get_column_names(), &["c"]);
+
+    // An empty array has no records to infer columns from.
+    let df = JsonReader::new(Cursor::new("[]")).finish()?;
+    assert_eq!(df.shape(), (0, 0));
+    let overwrite = Schema::from_iter([Field::new("a".into(), DataType::Int64)]);
+    let df = JsonReader::new(Cursor::new("[]"))
+        .with_schema_overwrite(&overwrite)
+        .finish()?;
+    assert_eq!(df.schema(), overwrite);
+    assert_eq!(df.height(), 0);
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "dtype-struct")]
+fn test_write_json_nested() -> PolarsResult<()> {
+    let list = Series::new(
+        "l".into(),
+        [
+            Series::new("".into(), [1i64, 2]),
+            Series::new("".into(), [3i64]),
+        ],
+    );
+    let strct = df!["x" => [1i64, 2], "y" => ["a", "b"]]?
+        .into_struct("s".into())
+        .into_series();
+    let mut df = DataFrame::new(vec![list, strct])?;
+    // Every chunk is serialized as its own batch.
+    df.vstack_mut(&df.clone())?;
+    assert_eq!(df.n_chunks(), 2);
+
+    let mut buf = Vec::new();
+    JsonWriter::new(&mut buf)
+        .with_json_format(JsonFormat::JsonLines)
+        .finish(&mut df)?;
+    let rows = [
+        r#"{"l":[1,2],"s":{"x":1,"y":"a"}}"#,
+        r#"{"l":[3],"s":{"x":2,"y":"b"}}"#,
+    ];
+    assert_eq!(
+        String::from_utf8(buf.clone()).unwrap(),
+        format!("{}\n{}\n{}\n{}\n", rows[0], rows[1], rows[0], rows[1])
+    );
+    let out = JsonReader::new(Cursor::new(buf))
+        .with_json_format(JsonFormat::JsonLines)
+        .finish()?;
+    assert!(out.equals(&df));
+
+    let mut buf = Vec::new();
+    JsonWriter::new(&mut buf)
+        .with_json_format(JsonFormat::Json)
+        .finish(&mut df)?;
+    assert_eq!(
+        String::from_utf8(buf.clone()).unwrap(),
+        format!("[{},{},{},{}]", rows[0], rows[1], rows[0], rows[1])
+    );
+    let out = JsonReader::new(Cursor::new(buf)).finish()?;
+    assert!(out.equals(&df));
+    Ok(())
+}
@@ -5,11 +5,12 @@ mod sidecar;
 use std::io::{Cursor, Read, Seek};
 
 use polars::io::parquet::read::ParquetReader;
-use polars::io::parquet::write::ParquetWriter;
+use polars::io::parquet::write::{ParquetCompression, ParquetWriteOptions, ParquetWriter};
 use polars::io::SerReader;
 use polars_core::df;
 use polars_core::prelude::*;
-use polars_parquet::parquet::compression::{BrotliLevel, CompressionOptions};
+use polars_parquet::parquet::compression::{BrotliLevel, Compression, CompressionOptions};
+use polars_parquet::parquet::encoding::Encoding;
 use polars_parquet::parquet::error::ParquetResult;
 use polars_parquet::parquet::metadata::{Descriptor, SchemaDescriptor};
 use polars_parquet::parquet::page::Page;
@@ -275,7 +276,7 @@ fn test_read_parquet_with_projection() {
         .finish()
         .unwrap();
     assert_eq!(df_read.shape(), (3, 2));
-    df_read.equals(&expected);
+    assert!(df_read.equals(&expected), "{df_read:?}");
 }
 
 #[test]
@@ -288,11 +289,184 @@ fn test_read_parquet_with_columns() {
         .expect("parquet writer");
     buf.set_position(0);
 
-    let expected = df!("b" => [2, 3, 4], "c" => [3, 4, 5]).unwrap();
+    // Columns are returned in the requested order.
+    let expected = df!("c" => [3, 4, 5], "b" => [2, 3, 4]).unwrap();
     let df_read = ParquetReader::new(buf)
         .with_columns(Some(vec!["c".to_string(), "b".to_string()]))
         .finish()
         .unwrap();
     assert_eq!(df_read.shape(), (3, 2));
-    df_read.equals(&expected);
+    assert!(df_read.equals(&expected), "{df_read:?}");
+}
+
+#[test]
+fn test_read_parquet_with_slice() -> PolarsResult<()> {
+    let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+    let mut df = df!("a" => [1, 2, 3, 4], "b" => ["w", "x", "y", "z"])?;
+
+    ParquetWriter::new(&mut buf)
+        .with_row_group_size(Some(2))
+        .finish(&mut df)?;
+    buf.set_position(0);
+
+    // The slice spans a row group boundary.
+    let df_read = ParquetReader::new(buf.clone())
+        .with_columns(Some(vec!["b".to_string()]))
+        .with_slice(Some((1, 2)))
+        .finish()?;
+    assert!(df_read.equals(&df!("b" => ["x", "y"])?));
+
+    // A slice past the end is truncated.
+    let df_read = ParquetReader::new(buf).with_slice(Some((3, 10))).finish()?;
+    assert!(df_read.equals(&df.slice(3, 1)), "{df_read:?}");
+    Ok(())
+}
+
+#[test]
+fn test_write_parquet_row_group_size() -> PolarsResult<()> {
+    let mut df = df!("a" => [1, 2, 3, 4, 5], "b" => ["v", "w", "x", "y", "z"])?;
+
+    for (row_group_size, expected) in [(None, vec![5]), (Some(2), vec![2, 2, 1])] {
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        ParquetWriter::new(&mut buf)
+            .with_row_group_size(row_group_size)
+            .finish(&mut df)?;
+        buf.set_position(0);
+
+        let mut reader = ParquetReader::new(buf);
+        let row_groups = reader
+            .get_metadata()?
+            .row_groups
+            .iter()
+            .map(|rg| rg.num_rows())
+            .collect::<Vec<_>>();
+        assert_eq!(row_groups, expected);
+        assert!(reader.finish()?.equals(&df));
+    }
+
+    // Small input chunks are merged, but never beyond the row group size. Chunks after the
+    // first few may still be larger than a row group.
+    let mut df = df!("a" => [1, 2, 3])?;
+    for chunk in [vec![4, 5, 6], vec![7, 8], vec![9, 10, 11, 12], vec![13]] {
+        df.vstack_mut(&df!("a" => chunk)?)?;
+    }
+    df.vstack_mut(&df!("a" => (14..21).collect::<Vec<i32>>())?)?;
+    assert_eq!(
+        df.get_columns()[0].chunk_lengths().collect::<Vec<_>>(),
+        [3, 3, 2, 4, 1, 7]
+    );
+
+    let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+    ParquetWriter::new(&mut buf)
+        .with_row_group_size(Some(5))
+        .finish(&mut df)?;
+    buf.set_position(0);
+
+    let mut reader = ParquetReader::new(buf);
+    let row_groups = reader
+        .get_metadata()?
+        .row_groups
+        .iter()
+        .map(|rg| rg.num_rows())
+        .collect::<Vec<_>>();
+    assert_eq!(row_groups, [3, 5, 5, 5, 2]);
+    assert!(reader.finish()?.equals(&df));
+
+    let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+    let out = ParquetWriter::new(&mut buf)
+        .with_row_group_size(Some(0))
+        .finish(&mut df);
+    assert!(matches!(out, Err(PolarsError::InvalidOperation(_))));
+    Ok(())
+}
+
+/// Write `df` and return the compression and whether dictionary encoding was used for every column.
+fn write_and_inspect_column_chunks(
+    df: &mut DataFrame,
+    configure: impl FnOnce(ParquetWriter<&mut Cursor<Vec<u8>>>) -> ParquetWriter<&mut Cursor<Vec<u8>>>,
+) -> PolarsResult<Vec<(Compression, bool)>> {
+    inspect_column_chunks(df, |buf, df| configure(ParquetWriter::new(buf)).finish(df))
+}
+
+fn inspect_column_chunks(
+    df: &mut DataFrame,
+    write: impl FnOnce(&mut Cursor<Vec<u8>>, &mut DataFrame) -> PolarsResult<u64>,
+) -> PolarsResult<Vec<(Compression, bool)>> {
+    let mut buf = Cursor::new(Vec::new());
+    write(&mut buf, df)?;
+    buf.set_position(0);
+
+    let mut reader = ParquetReader::new(buf);
+    let metadata = reader.get_metadata()?.clone();
+    let out = df
+        .get_column_names()
+        .into_iter()
+        .map(|name| {
+            let column = metadata.row_groups[0]
+                .columns_under_root_iter(name)
+                .next()
+                .unwrap();
+            let is_dictionary = column
+                .column_encoding()
+                .iter()
+                .any(|e| matches!(Encoding::try_from(*e), Ok(Encoding::RleDictionary)));
+            (column.compression(), is_dictionary)
+        })
+        .collect();
+    assert!(reader.finish()?.equals(df));
+    Ok(out)
+}
+
+#[test]
+fn test_write_parquet_dictionary_and_column_compression() -> PolarsResult<()> {
+    let mut df = df!("a" => [1, 1, 2, 2], "b" => ["x", "x", "y", "y"])?;
+
+    let out = write_and_inspect_column_chunks(&mut df, |w| w)?;
+    assert_eq!(out, [(Compression::Zstd, true), (Compression::Zstd, true)]);
+
+    let out = write_and_inspect_column_chunks(&mut df, |w| {
+        w.with_compression(ParquetCompression::Snappy)
+            .with_dictionary_encoding(false)
+            .with_column_compression(PlHashMap::from_iter([(
+                "b".into(),
+                ParquetCompression::Uncompressed,
+            )]))
+    })?;
+    assert_eq!(
+        out,
+        [
+            (Compression::Snappy, false),
+            (Compression::Uncompressed, false)
+        ]
+    );
+
+    let out = write_and_inspect_column_chunks(&mut df, |w| {
+        w.with_column_compression(PlHashMap::from_iter([(
+            "c".into(),
+            ParquetCompression::Snappy,
+        )]))
+    });
+    assert!(matches!(out, Err(PolarsError::ColumnNotFound(_))));
+    Ok(())
+}
+
+#[test]
+fn test_write_parquet_options_dictionary_and_column_compression() -> PolarsResult<()> {
+    let mut df = df!("a" => [1, 1, 2, 2], "b" => ["x", "x", "y", "y"])?;
+    let options = ParquetWriteOptions {
+        compression: ParquetCompression::Snappy,
+        dictionary_encoding: false,
+        column_compression: vec![("b".into(), ParquetCompression::Uncompressed)],
+        ..Default::default()
+    };
+
+    let out = inspect_column_chunks(&mut df, |buf, df| options.to_writer(buf).finish(df))?;
+    assert_eq!(
+        out,
+        [
+            (Compression::Snappy, false),
+            (Compression::Uncompressed, false)
+        ]
+    );
+    Ok(())
 }
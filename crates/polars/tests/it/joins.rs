@@ -56,3 +56,111 @@ fn join_empty_datasets() -> PolarsResult<()> {
 
     Ok(())
 }
+
+#[test]
+#[cfg(feature = "lazy")]
+fn join_upcasts_numeric_keys() -> PolarsResult<()> {
+    let left = df! {
+        "a" => [1i32, 2, 3],
+        "b" => ["x", "y", "z"],
+    }?;
+    let right = df! {
+        "a" => [1i64, 3],
+        "c" => [true, false],
+    }?;
+
+    let out = left
+        .clone()
+        .lazy()
+        .join(
+            right.clone().lazy(),
+            [col("a")],
+            [col("a")],
+            JoinArgs::new(JoinType::Inner),
+        )
+        .collect()?;
+    assert_eq!(out.column("a")?.dtype(), &DataType::Int64);
+    assert_eq!(Vec::from(out.column("a")?.i64()?), &[Some(1), Some(3)]);
+
+    // Int32 and UInt64 have no lossless supertype.
+    let right = right
+        .lazy()
+        .with_column(col("a").cast(DataType::UInt64))
+        .collect()?;
+    let out = left
+        .lazy()
+        .join(
+            right.lazy(),
+            [col("a")],
+            [col("a")],
+            JoinArgs::new(JoinType::Inner),
+        )
+        .collect();
+    assert!(out.is_err());
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "lazy")]
+fn join_without_coercing_keys() -> PolarsResult<()> {
+    let left = df! {
+        "a" => [1i32, 2, 3],
+    }?;
+    let right = df! {
+        "a" => [1i64, 3],
+    }?;
+
+    let out = left
+        .lazy()
+        .join(
+            right.lazy(),
+            [col("a")],
+            [col("a")],
+            JoinArgs::new(JoinType::Inner).with_coerce_keys(false),
+        )
+        .collect();
+    assert!(matches!(out, Err(PolarsError::ComputeError(_))));
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "lazy", feature = "dtype-categorical"))]
+fn join_coerces_categorical_and_string_keys() -> PolarsResult<()> {
+    let left = df! {
+        "a" => ["x", "y", "z"],
+        "b" => [1, 2, 3],
+    }?
+    .lazy()
+    .with_column(col("a").cast(DataType::Categorical(None, Default::default())));
+    let right = df! {
+        "a" => ["z", "x"],
+        "c" => [true, false],
+    }?
+    .lazy();
+
+    let out = left
+        .clone()
+        .join(
+            right.clone(),
+            [col("a")],
+            [col("a")],
+            JoinArgs::new(JoinType::Left),
+        )
+        .collect()?;
+    assert_eq!(out.column("a")?.dtype(), &DataType::String);
+    assert_eq!(
+        Vec::from(out.column("c")?.bool()?),
+        &[Some(false), None, Some(true)]
+    );
+
+    let out = left
+        .join(
+            right,
+            [col("a")],
+            [col("a")],
+            JoinArgs::new(JoinType::Left).with_coerce_keys(false),
+        )
+        .collect();
+    assert!(out.is_err());
+    Ok(())
+}
@@ -65,3 +65,216 @@ fn test_apply_multiple_error() {
         .collect()
         .unwrap();
 }
+
+#[test]
+fn test_quantile_interpolation_consistent() -> PolarsResult<()> {
+    use QuantileInterpolOptions::*;
+
+    let values = [Some(4.0), None, Some(1.0), Some(3.0), Some(2.0)];
+    let df = df![
+        "g" => [1, 1, 1, 1, 1],
+        "v" => values
+    ]?;
+
+    // The non-null values are [1, 2, 3, 4], so q = 0.4 falls between 2 and 3.
+    for (method, expected) in [
+        (Nearest, 2.0),
+        (Lower, 2.0),
+        (Higher, 3.0),
+        (Midpoint, 2.5),
+        (Linear, 2.2),
+    ] {
+        let eager = df.column("v")?.f64()?.quantile(0.4, method)?.unwrap();
+        assert!((eager - expected).abs() < 1e-9, "{method:?}: {eager}");
+
+        let select = df
+            .clone()
+            .lazy()
+            .select([col("v").quantile(lit(0.4), method)])
+            .collect()?;
+        let select = select.column("v")?.f64()?.get(0).unwrap();
+        assert!((select - eager).abs() < 1e-9, "{method:?}: {select}");
+
+        let group_by = df
+            .clone()
+            .lazy()
+            .group_by([col("g")])
+            .agg([col("v").quantile(lit(0.4), method)])
+            .collect()?;
+        let group_by = group_by.column("v")?.f64()?.get(0).unwrap();
+        assert!((group_by - eager).abs() < 1e-9, "{method:?}: {group_by}");
+    }
+    Ok(())
+}
+
+#[test]
+fn test_weighted_mean() -> PolarsResult<()> {
+    let df = df![
+        "g" => ["a", "a", "a", "b", "b"],
+        "v" => [Some(1), Some(2), None, Some(4), Some(10)],
+        "w" => [Some(1.0), Some(3.0), Some(5.0), None, Some(2.0)]
+    ]?;
+
+    let out = df
+        .clone()
+        .lazy()
+        .select([col("v").weighted_mean(col("w"))])
+        .collect()?;
+    // The null value and the null weight are skipped: (1 + 6 + 20) / (1 + 3 + 2).
+    assert_eq!(out.column("v")?.f64()?.get(0), Some(4.5));
+
+    let out = df
+        .lazy()
+        .group_by([col("g")])
+        .agg([col("v").weighted_mean(col("w"))])
+        .sort(["g"], Default::default())
+        .collect()?;
+    assert_eq!(
+        Vec::from(out.column("v")?.f64()?),
+        &[Some(1.75), Some(10.0)]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_boolean_sum_mean() -> PolarsResult<()> {
+    let df = df![
+        "g" => [1, 1, 1, 2, 2],
+        "b" => [Some(true), None, Some(false), Some(true), Some(true)]
+    ]?;
+
+    let b = df.column("b")?;
+    assert_eq!(b.bool()?.sum(), Some(3));
+    assert_eq!(b.mean(), Some(0.75));
+
+    let out = df
+        .clone()
+        .lazy()
+        .select([col("b").sum().alias("sum"), col("b").mean().alias("mean")])
+        .collect()?;
+    assert_eq!(out.column("sum")?.dtype(), &IDX_DTYPE);
+    assert_eq!(out.column("sum")?.get(0)?, AnyValue::from(3 as IdxSize));
+    assert_eq!(out.column("mean")?.f64()?.get(0), Some(0.75));
+
+    for streaming in [false, true] {
+        let out = df
+            .clone()
+            .lazy()
+            .group_by([col("g")])
+            .agg([col("b").sum().alias("sum"), col("b").mean().alias("mean")])
+            .sort(["g"], Default::default())
+            .with_streaming(streaming)
+            .collect()?;
+        assert_eq!(Vec::from(out.column("sum")?.idx()?), &[Some(1), Some(2)]);
+        assert_eq!(
+            Vec::from(out.column("mean")?.f64()?),
+            &[Some(0.5), Some(1.0)]
+        );
+    }
+    Ok(())
+}
+
+#[test]
+fn test_weighted_sum_and_quantile() -> PolarsResult<()> {
+    let df = df![
+        "g" => ["a", "a", "a", "b", "b"],
+        "v" => [Some(3), Some(1), None, Some(4), Some(10)],
+        "w" => [Some(1.0), Some(3.0), Some(5.0), None, Some(2.0)]
+    ]?;
+
+    let out = df
+        .clone()
+        .lazy()
+        .select([
+            col("v").weighted_sum(col("w")).alias("sum"),
+            col("v").weighted_quantile(col("w"), 0.0).alias("q0"),
+            col("v").weighted_quantile(col("w"), 0.5).alias("q50"),
+            col("v").weighted_quantile(col("w"), 0.6).alias("q60"),
+            col("v").weighted_quantile(col("w"), 1.0).alias("q100"),
+        ])
+        .collect()?;
+    // The null value and the null weight are skipped: 3 + 3 + 20.
+    assert_eq!(out.column("sum")?.f64()?.get(0), Some(26.0));
+    // The sorted values 1, 3, 10 have cumulative weights 3, 4, 6 out of 6.
+    assert_eq!(out.column("q0")?.i32()?.get(0), Some(1));
+    assert_eq!(out.column("q50")?.i32()?.get(0), Some(1));
+    assert_eq!(out.column("q60")?.i32()?.get(0), Some(3));
+    assert_eq!(out.column("q100")?.i32()?.get(0), Some(10));
+
+    let out = df
+        .lazy()
+        .group_by([col("g")])
+        .agg([
+            col("v").weighted_sum(col("w")).alias("sum"),
+            col("v").weighted_quantile(col("w"), 0.5).alias("q50"),
+        ])
+        .sort(["g"], Default::default())
+        .collect()?;
+    assert_eq!(
+        Vec::from(out.column("sum")?.f64()?),
+        &[Some(6.0), Some(20.0)]
+    );
+    assert_eq!(Vec::from(out.column("q50")?.i32()?), &[Some(1), Some(10)]);
+    Ok(())
+}
+
+#[test]
+fn test_weighted_quantile_invalid() -> PolarsResult<()> {
+    let df = df![
+        "v" => [1, 2, 3],
+        "w" => [1.0, -1.0, 2.0]
+    ]?;
+
+    let err = df
+        .clone()
+        .lazy()
+        .select([col("v").weighted_quantile(col("w"), 0.5)])
+        .collect()
+        .unwrap_err();
+    assert!(err.to_string().contains("non-negative weights"), "{err}");
+
+    let err = df
+        .lazy()
+        .select([col("v").weighted_quantile(col("w").abs(), 1.5)])
+        .collect()
+        .unwrap_err();
+    assert!(err.to_string().contains("between 0.0 and 1.0"), "{err}");
+    Ok(())
+}
+
+#[test]
+fn test_sum_with_overflow() -> PolarsResult<()> {
+    let df = df![
+        "g" => ["a", "a", "b", "b"],
+        "v" => [Some(i32::MAX), Some(1), None, Some(2)]
+    ]?;
+    let sum = |policy| {
+        df.clone()
+            .lazy()
+            .select([col("v").sum_with_overflow(policy)])
+            .collect()
+    };
+
+    let out = sum(OverflowPolicy::Wrap)?;
+    assert_eq!(out.column("v")?.i32()?.get(0), Some(i32::MIN + 2));
+    let out = sum(OverflowPolicy::Saturate)?;
+    assert_eq!(out.column("v")?.i32()?.get(0), Some(i32::MAX));
+    let out = sum(OverflowPolicy::Upcast)?;
+    assert_eq!(out.column("v")?.i64()?.get(0), Some(i32::MAX as i64 + 3));
+    assert!(matches!(
+        sum(OverflowPolicy::Error),
+        Err(PolarsError::ComputeError(_))
+    ));
+
+    let out = df
+        .lazy()
+        .group_by([col("g")])
+        .agg([col("v").sum_with_overflow(OverflowPolicy::Saturate)])
+        .sort(["g"], Default::default())
+        .collect()?;
+    assert_eq!(
+        Vec::from(out.column("v")?.i32()?),
+        &[Some(i32::MAX), Some(2)]
+    );
+    Ok(())
+}
@@ -365,3 +365,67 @@ fn test_binary_group_consistency() -> PolarsResult<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_arithmetic_with_overflow() -> PolarsResult<()> {
+    let df = df![
+        "a" => [Some(100i8), Some(-100), None],
+        "b" => [Some(100i8), Some(100), Some(1)],
+        "u" => [1u8, 2, 3]
+    ]?;
+
+    let out = df
+        .clone()
+        .lazy()
+        .select([
+            col("a")
+                .add_with_overflow(col("b"), OverflowPolicy::Saturate)
+                .alias("add"),
+            col("a")
+                .sub_with_overflow(col("b"), OverflowPolicy::Saturate)
+                .alias("sub"),
+            col("a")
+                .mul_with_overflow(lit(2), OverflowPolicy::Upcast)
+                .alias("mul"),
+            col("u")
+                .sub_with_overflow(lit(2), OverflowPolicy::Saturate)
+                .alias("u_sub"),
+        ])
+        .collect()?;
+    assert_eq!(
+        Vec::from(out.column("add")?.i8()?),
+        &[Some(i8::MAX), Some(0), None]
+    );
+    assert_eq!(
+        Vec::from(out.column("sub")?.i8()?),
+        &[Some(0), Some(i8::MIN), None]
+    );
+    assert_eq!(
+        Vec::from(out.column("mul")?.i64()?),
+        &[Some(200), Some(-200), None]
+    );
+    assert_eq!(
+        Vec::from(out.column("u_sub")?.u8()?),
+        &[Some(0), Some(0), Some(1)]
+    );
+
+    let out = df
+        .lazy()
+        .select([col("a").add_with_overflow(col("b"), OverflowPolicy::Error)])
+        .collect();
+    assert!(matches!(out, Err(PolarsError::ComputeError(_))));
+
+    // The supertype of `Int64` and `UInt64` can't hold all their values.
+    let out = df![
+        "i" => [1i64],
+        "u" => [1u64]
+    ]?
+    .lazy()
+    .select([col("i").add_with_overflow(col("u"), OverflowPolicy::Saturate)])
+    .collect();
+    assert!(out
+        .unwrap_err()
+        .to_string()
+        .contains("requires an integer supertype"));
+    Ok(())
+}
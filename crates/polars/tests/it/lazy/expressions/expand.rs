@@ -44,3 +44,104 @@ fn test_expand_datetimes_3042() -> PolarsResult<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_expand_dtype_regex_and_exclude() -> PolarsResult<()> {
+    let df = df![
+        "id" => [1i64, 2],
+        "sensor_a" => [1.0, 2.0],
+        "sensor_b" => [3i64, 4],
+        "sensor_c" => ["x", "y"],
+        "other" => [5.0, 6.0],
+    ]?;
+    let names = |exprs: Vec<Expr>| -> PolarsResult<Vec<PlSmallStr>> {
+        let out = df.clone().lazy().select(exprs).collect()?;
+        Ok(out.get_column_names_owned())
+    };
+
+    assert_eq!(
+        names(vec![dtype_cols([DataType::Int64, DataType::Float64])])?,
+        &["id", "sensor_a", "sensor_b", "other"]
+    );
+    assert_eq!(
+        names(vec![col("^sensor_.*$")])?,
+        &["sensor_a", "sensor_b", "sensor_c"]
+    );
+    assert_eq!(
+        names(vec![col("*").exclude(["id", "^sensor_.*$"])])?,
+        &["other"]
+    );
+    assert_eq!(
+        names(vec![col("^sensor_.*$").exclude_dtype([DataType::String])])?,
+        &["sensor_a", "sensor_b"]
+    );
+    assert_eq!(
+        names(vec![dtype_col(&DataType::Float64).exclude(["other"])])?,
+        &["sensor_a"]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_expand_multiple_columns_in_contexts() -> PolarsResult<()> {
+    let df = df![
+        "g" => ["x", "x", "y"],
+        "a" => [1i64, 2, 3],
+        "b" => [10.0, 20.0, 30.0],
+        "c" => ["p", "q", "r"],
+    ]?;
+
+    let out = df
+        .clone()
+        .lazy()
+        .with_columns([cols(["a", "b"]).cast(DataType::Float64).name().suffix("_f")])
+        .collect()?;
+    assert_eq!(out.get_column_names(), &["g", "a", "b", "c", "a_f", "b_f"]);
+
+    let out = df
+        .clone()
+        .lazy()
+        .group_by_stable([col("g")])
+        .agg([all().exclude(["g", "c"]).sum()])
+        .collect()?;
+    let expected = df![
+        "g" => ["x", "y"],
+        "a" => [3i64, 3],
+        "b" => [30.0, 30.0],
+    ]?;
+    assert!(out.equals(&expected));
+
+    // A wildcard expands to every column, in schema order.
+    let out = df
+        .lazy()
+        .select([col("*").exclude_dtype([DataType::String]).max()])
+        .collect()?;
+    assert_eq!(out.get_column_names(), &["a", "b"]);
+    assert_eq!(out.shape(), (1, 2));
+    Ok(())
+}
+
+#[test]
+fn test_expand_name_helpers() -> PolarsResult<()> {
+    let df = df![
+        "a" => [1, 2],
+        "B" => [3, 4],
+    ]?;
+
+    let out = df
+        .lazy()
+        .select([
+            all().sum().name().prefix("agg_"),
+            all().max().name().suffix("_right"),
+            all()
+                .name()
+                .map(|name| Ok(format!("{}_{}", name, name.len()).into())),
+            col("B").name().to_lowercase(),
+        ])
+        .collect()?;
+    assert_eq!(
+        out.get_column_names(),
+        &["agg_a", "agg_B", "a_right", "B_right", "a_1", "B_1", "b"]
+    );
+    Ok(())
+}
@@ -6,6 +6,8 @@ mod filter;
 mod is_in;
 mod literals;
 mod slice;
+#[cfg(feature = "dtype-struct")]
+mod struct_;
 mod window;
 
 use super::*;
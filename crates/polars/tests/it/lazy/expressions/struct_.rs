@@ -0,0 +1,74 @@
+use super::*;
+
+fn struct_df() -> PolarsResult<DataFrame> {
+    df![
+        "a" => [1, 2, 3],
+        "b" => ["x", "y", "z"]
+    ]
+}
+
+#[test]
+fn test_struct_pack_and_field() -> PolarsResult<()> {
+    let out = struct_df()?
+        .lazy()
+        .select([as_struct(vec![col("a"), col("b")]).alias("s")])
+        .select([
+            col("s").struct_().field_by_name("b"),
+            col("s").struct_().field_by_index(0),
+        ])
+        .collect()?;
+
+    assert_eq!(out.get_column_names(), &["b", "a"]);
+    assert_eq!(
+        Vec::from(out.column("a")?.i32()?),
+        &[Some(1), Some(2), Some(3)]
+    );
+    assert_eq!(
+        Vec::from(out.column("b")?.str()?),
+        &[Some("x"), Some("y"), Some("z")]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_struct_rename_fields_and_unnest() -> PolarsResult<()> {
+    let out = struct_df()?
+        .lazy()
+        .select([as_struct(vec![col("a"), col("b")])
+            .struct_()
+            .rename_fields(["c", "d"])
+            .alias("s")])
+        .unnest(["s"])
+        .collect()?;
+
+    assert_eq!(out.get_column_names(), &["c", "d"]);
+    assert_eq!(out.column("c")?.dtype(), &DataType::Int32);
+    assert_eq!(out.column("d")?.dtype(), &DataType::String);
+    Ok(())
+}
+
+#[test]
+fn test_struct_field_not_found() -> PolarsResult<()> {
+    let out = struct_df()?
+        .lazy()
+        .select([as_struct(vec![col("a"), col("b")])
+            .struct_()
+            .field_by_name("missing")])
+        .collect();
+
+    assert!(out.is_err());
+    Ok(())
+}
+
+#[test]
+fn test_map_fields_non_struct() -> PolarsResult<()> {
+    let out = struct_df()?
+        .lazy()
+        .select([col("a")
+            .name()
+            .map_fields(Arc::new(|name| format!("{name}_x").into()))])
+        .collect();
+
+    assert!(out.is_err());
+    Ok(())
+}
@@ -399,3 +399,53 @@ fn test_window_map_empty_df_3542() -> PolarsResult<()> {
     assert_eq!(out.height(), 0);
     Ok(())
 }
+
+#[test]
+#[cfg(all(
+    feature = "rolling_window",
+    feature = "rolling_window_by",
+    feature = "cum_agg"
+))]
+fn test_rolling_and_expanding_over_groups() -> PolarsResult<()> {
+    let df = df![
+        "g" => ["a", "b", "a", "b", "a", "b", "a"],
+        "t" => [1i32, 2, 3, 4, 5, 6, 7],
+        "v" => [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0],
+    ]?;
+
+    let out = df
+        .lazy()
+        .select([
+            col("v")
+                .rolling_mean(RollingOptionsFixedWindow {
+                    window_size: 2,
+                    min_periods: 1,
+                    ..Default::default()
+                })
+                .over([col("g")])
+                .alias("mean"),
+            col("v")
+                .rolling_sum_by(
+                    col("t").cast(DataType::Date),
+                    RollingOptionsDynamicWindow {
+                        window_size: Duration::parse("3d"),
+                        min_periods: 1,
+                        closed_window: ClosedWindow::Right,
+                        fn_params: None,
+                    },
+                )
+                .over([col("g")])
+                .alias("sum_by"),
+            col("v").cum_sum(false).over([col("g")]).alias("cum_sum"),
+        ])
+        .collect()?;
+
+    // Every window only sees the values of its own group.
+    let expected = df![
+        "mean" => [1.0, 2.0, 2.0, 3.0, 4.0, 5.0, 6.0],
+        "sum_by" => [1.0, 2.0, 4.0, 6.0, 8.0, 10.0, 12.0],
+        "cum_sum" => [1.0, 2.0, 4.0, 6.0, 9.0, 12.0, 16.0],
+    ]?;
+    assert!(out.equals(&expected), "{out:?}");
+    Ok(())
+}
@@ -113,3 +113,34 @@ fn fuzz_exprs() {
         }
     }
 }
+
+#[test]
+#[cfg(feature = "peaks")]
+fn test_peak_min_max() -> PolarsResult<()> {
+    let lf = df![
+        "a" => [1, 3, 2, 2, 0, 4],
+        "s" => ["a", "b", "c", "d", "e", "f"],
+    ]?
+    .lazy();
+    let q = lf.clone().select([
+        col("a").peak_max().alias("max"),
+        col("a").peak_min().alias("min"),
+    ]);
+
+    let schema = q.clone().collect_schema()?;
+    assert_eq!(schema.get("max"), Some(&DataType::Boolean));
+    assert_eq!(schema.get("min"), Some(&DataType::Boolean));
+
+    let out = q.collect()?;
+    assert_eq!(
+        Vec::from(out.column("max")?.bool()?),
+        [false, true, false, false, false, true].map(Some)
+    );
+    assert_eq!(
+        Vec::from(out.column("min")?.bool()?),
+        [false, false, false, false, true, false].map(Some)
+    );
+
+    assert!(lf.select([col("s").peak_max()]).collect().is_err());
+    Ok(())
+}
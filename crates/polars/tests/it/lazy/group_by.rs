@@ -181,3 +181,46 @@ fn test_filter_aggregated_expression() -> PolarsResult<()> {
     );
     Ok(())
 }
+
+#[test]
+fn test_group_by_maintain_order() -> PolarsResult<()> {
+    // Enough groups to make an accidentally ordered hash-grouping result unlikely.
+    let keys = (0..1000).map(|i| (i * 7919) % 1000).collect::<Vec<i32>>();
+    let df = df![
+        "k1" => keys.iter().chain(keys.iter()).copied().collect::<Vec<_>>(),
+        "k2" => (0..2000).map(|i| i % 2).collect::<Vec<i32>>(),
+        "v" => (0..2000).collect::<Vec<i32>>(),
+    ]?;
+
+    let eager_keys = df.group_by_stable(["k1"])?.keys();
+    assert!(eager_keys[0].equals(&Series::new("k1".into(), &keys)));
+    // Every key shows up at row `i` and again at row `i + 1000`.
+    let expected = df![
+        "k1" => &keys,
+        "v" => (0..1000).map(|i| 2 * i + 1000).collect::<Vec<i32>>(),
+    ]?;
+
+    for streaming in [false, true] {
+        let out = df
+            .clone()
+            .lazy()
+            .group_by_stable([col("k1")])
+            .agg([col("v").sum()])
+            .with_streaming(streaming)
+            .collect()?;
+        assert!(out.equals(&expected));
+
+        let out = df
+            .clone()
+            .lazy()
+            .group_by_stable([col("k2"), col("k1")])
+            .agg([col("v").first()])
+            .with_streaming(streaming)
+            .collect()?;
+        let first = df.slice(0, 1000);
+        assert!(out.column("k1")?.equals(first.column("k1")?));
+        assert!(out.column("k2")?.equals(first.column("k2")?));
+        assert!(out.column("v")?.equals(first.column("v")?));
+    }
+    Ok(())
+}
@@ -272,3 +272,24 @@ fn test_group_by_on_lists() -> PolarsResult<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_repeat() -> PolarsResult<()> {
+    let out = DataFrame::empty()
+        .lazy()
+        .select([
+            repeat(lit(1i32), lit(3)).alias("ones"),
+            repeat(lit("a"), lit(3)).alias("s"),
+        ])
+        .collect()?;
+    assert_eq!(out.shape(), (3, 2));
+    assert_eq!(Vec::from(out.column("ones")?.i32()?), &[Some(1); 3]);
+
+    let df = df!["a" => [1, 2]]?;
+    let out = df
+        .lazy()
+        .select([repeat(col("a").filter(lit(false)), lit(3))])
+        .collect();
+    assert!(out.is_err());
+    Ok(())
+}
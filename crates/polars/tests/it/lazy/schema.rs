@@ -1 +1,33 @@
+use super::*;
 
+#[test]
+fn test_arithmetic_supertype_eager_lazy_consistent() -> PolarsResult<()> {
+    use DataType::*;
+
+    let cases = [
+        (Int32, Float64, Float64),
+        (UInt32, Int64, Int64),
+        (UInt8, Int8, Int16),
+        (Int64, UInt64, Int64),
+        (Float32, Int64, Float64),
+        (Boolean, Int32, Int32),
+    ];
+    for (l, r, expected) in cases {
+        let df = df![
+            "l" => [1, 2, 3],
+            "r" => [4, 5, 6]
+        ]?
+        .lazy()
+        .select([col("l").cast(l.clone()), col("r").cast(r.clone())])
+        .collect()?;
+
+        let eager = (df.column("l")? + df.column("r")?)?;
+        assert_eq!(eager.dtype(), &expected, "{l} + {r}");
+
+        let lf = df.lazy().select([(col("l") + col("r")).alias("out")]);
+        let schema = lf.clone().collect_schema()?;
+        assert_eq!(schema.get("out"), Some(&expected), "{l} + {r}");
+        assert_eq!(lf.collect()?.column("out")?.dtype(), &expected, "{l} + {r}");
+    }
+    Ok(())
+}
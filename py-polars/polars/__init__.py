@@ -178,6 +178,7 @@ from polars.lazyframe import GPUEngine, LazyFrame
 from polars.meta import (
     build_info,
     get_index_type,
+    set_num_threads,
     show_versions,
     thread_pool_size,
     threadpool_size,
@@ -369,6 +370,7 @@ __all__ = [
     # polars.meta
     "build_info",
     "get_index_type",
+    "set_num_threads",
     "show_versions",
     "thread_pool_size",
     "threadpool_size",
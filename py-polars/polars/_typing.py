@@ -110,10 +110,12 @@ FillNullStrategy: TypeAlias = Literal[
 FloatFmt: TypeAlias = Literal["full", "mixed"]
 IndexOrder: TypeAlias = Literal["c", "fortran"]
 IpcCompression: TypeAlias = Literal["uncompressed", "lz4", "zstd"]
+JoinAlgorithm: TypeAlias = Literal["auto", "hash", "sort_merge", "broadcast"]
 JoinValidation: TypeAlias = Literal["m:m", "m:1", "1:m", "1:1"]
 Label: TypeAlias = Literal["left", "right", "datapoint"]
 NonExistent: TypeAlias = Literal["raise", "null"]
 NullBehavior: TypeAlias = Literal["ignore", "drop"]
+OverflowPolicy: TypeAlias = Literal["wrap", "saturate", "error", "upcast"]
 ParallelStrategy: TypeAlias = Literal[
     "auto", "columns", "row_groups", "prefiltered", "none"
 ]
@@ -22,11 +22,11 @@ def pandas_series_to_arrow(
     ----------
     values : :class:`pandas.Series` or :class:`pandas.Index`.
         Series to convert to arrow
+    length : int, optional
+        In case all values are null, create a null array of this length.
+        If unset, length is inferred from values.
     nan_to_null : bool, default = True
         Interpret `NaN` as missing values.
-    length : int, optional
-        in case all values are null, create a null array of this length.
-        if unset, length is inferred from values.
 
     Returns
     -------
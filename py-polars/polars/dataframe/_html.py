@@ -73,10 +73,11 @@ class HTMLFormatter:
         else:
             self.row_idx = range(df.height)
         if max_cols < df.width:
+            half, rest = divmod(max_cols, 2)
             self.col_idx = [
-                *list(range(max_cols // 2)),
+                *list(range(half + rest)),
                 -1,
-                *list(range(df.width - max_cols // 2, df.width)),
+                *list(range(df.width - half, df.width)),
             ]
         else:
             self.col_idx = range(df.width)
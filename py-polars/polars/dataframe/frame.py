@@ -147,6 +147,7 @@ if TYPE_CHECKING:
         IntoExpr,
         IntoExprColumn,
         IpcCompression,
+        JoinAlgorithm,
         JoinStrategy,
         JoinValidation,
         Label,
@@ -3645,6 +3646,8 @@ class DataFrame:
         statistics: bool | str | dict[str, bool] = True,
         row_group_size: int | None = None,
         data_page_size: int | None = None,
+        dictionary_encoding: bool = True,
+        column_compression: dict[str, ParquetCompression] | None = None,
         use_pyarrow: bool = False,
         pyarrow_options: dict[str, Any] | None = None,
         partition_by: str | Sequence[str] | None = None,
@@ -3691,6 +3694,15 @@ class DataFrame:
             Size of the row groups in number of rows. Defaults to 512^2 rows.
         data_page_size
             Size of the data page in bytes. Defaults to 1024^2 bytes.
+        dictionary_encoding
+            Allow dictionary encoding of columns. If `False`, all columns except
+            categoricals are written with plain encoding.
+        column_compression
+            Compression of individual columns, as a mapping from column name to one of
+            the `compression` options, which then use their default level. Only
+            top-level columns can be given; all fields of a nested column share its
+            compression. Columns that are not given use `compression`. Cannot be
+            combined with `use_pyarrow`.
         use_pyarrow
             Use C++ parquet implementation vs Rust parquet implementation.
             At the moment C++ supports more features.
@@ -3753,6 +3765,9 @@ class DataFrame:
             if statistics == "full" or isinstance(statistics, dict):
                 msg = "write_parquet with `use_pyarrow=True` allows only boolean values for `statistics`"
                 raise ValueError(msg)
+            if column_compression is not None:
+                msg = "`column_compression` cannot be combined with `use_pyarrow=True`"
+                raise ValueError(msg)
 
             tbl = self.to_arrow()
             data = {}
@@ -3778,6 +3793,7 @@ class DataFrame:
             pyarrow_options["write_statistics"] = statistics
             pyarrow_options["row_group_size"] = row_group_size
             pyarrow_options["data_page_size"] = data_page_size
+            pyarrow_options["use_dictionary"] = dictionary_encoding
 
             if pyarrow_options.get("partition_cols"):
                 pa.parquet.write_to_dataset(
@@ -3817,6 +3833,10 @@ class DataFrame:
             if isinstance(partition_by, str):
                 partition_by = [partition_by]
 
+            column_compressions = (
+                None if column_compression is None else list(column_compression.items())
+            )
+
             self._df.write_parquet(
                 file,
                 compression,
@@ -3824,6 +3844,8 @@ class DataFrame:
                 statistics,
                 row_group_size,
                 data_page_size,
+                dictionary_encoding,
+                column_compressions,
                 partition_by=partition_by,
                 partition_chunk_size_bytes=partition_chunk_size_bytes,
             )
@@ -4806,8 +4828,8 @@ class DataFrame:
         data = [_parse_column(s, dtype) for s, dtype in self.schema.items()]
 
         # determine column layout widths
-        max_col_name = max((len(col_name) for col_name, _, _ in data))
-        max_col_dtype = max((len(dtype_str) for _, dtype_str, _ in data))
+        max_col_name = max((len(col_name) for col_name, _, _ in data), default=0)
+        max_col_dtype = max((len(dtype_str) for _, dtype_str, _ in data), default=0)
 
         # print header
         output = StringIO()
@@ -6929,6 +6951,8 @@ class DataFrame:
         suffix: str = "_right",
         validate: JoinValidation = "m:m",
         join_nulls: bool = False,
+        coerce_keys: bool = True,
+        algorithm: JoinAlgorithm = "auto",
         coalesce: bool | None = None,
     ) -> DataFrame:
         """
@@ -6985,6 +7009,32 @@ class DataFrame:
 
         join_nulls
             Join on null values. By default null values will never produce matches.
+        coerce_keys
+            Cast join keys of different but compatible data types to a common type
+            before joining, instead of raising an error. Integer and float keys are
+            cast to a supertype that holds all their values (e.g. `Int32` and `Int64`
+            are joined as `Int64`), and categorical keys joined with string keys are
+            cast to `String`. The key columns in the output have the casted type.
+        algorithm : {'auto', 'hash', 'sort_merge', 'broadcast'}
+            Algorithm used to join on a single key in an inner, left or right join.
+            Other joins always use a hash join.
+
+            * *auto*
+                Use a sort-merge join if the keys are sorted, a broadcast join if one
+                side is tiny, and a hash join otherwise.
+            * *hash*
+                Build a hash table from the shorter side.
+            * *sort_merge*
+                Merge the sorted keys, sorting them first where needed. The keys must
+                be numeric and may not contain nulls.
+            * *broadcast*
+                Compare the keys with every value of the build side instead of
+                building a hash table. The build side is the shorter side in an inner
+                join, and the right (left) side in a left (right) join.
+
+            .. note::
+                This is only used by the in-memory engine, and join validation
+                requires the 'auto' or 'hash' algorithm.
         coalesce
             Coalescing behavior (merging of join columns).
 
@@ -7090,6 +7140,8 @@ class DataFrame:
                 suffix=suffix,
                 validate=validate,
                 join_nulls=join_nulls,
+                coerce_keys=coerce_keys,
+                algorithm=algorithm,
                 coalesce=coalesce,
             )
             .collect(_eager=True)
@@ -7281,7 +7333,7 @@ class DataFrame:
             return wrap_s(out).to_frame()
 
     def hstack(
-        self, columns: list[Series] | DataFrame, *, in_place: bool = False
+        self, columns: list[Series] | Series | DataFrame, *, in_place: bool = False
     ) -> DataFrame:
         """
         Return a new DataFrame grown horizontally by stacking multiple Series to it.
@@ -7289,7 +7341,8 @@ class DataFrame:
         Parameters
         ----------
         columns
-            Series to stack.
+            Series to stack. A single Series or the columns of a DataFrame are
+            also accepted.
         in_place
             Modify in place.
 
@@ -7315,7 +7368,9 @@ class DataFrame:
         │ 3   ┆ 8   ┆ c   ┆ 30    │
         └─────┴─────┴─────┴───────┘
         """
-        if not isinstance(columns, list):
+        if isinstance(columns, pl.Series):
+            columns = [columns]
+        elif not isinstance(columns, list):
             columns = columns.get_columns()
         if in_place:
             self._df.hstack_mut([s._s for s in columns])
@@ -81,6 +81,7 @@ if TYPE_CHECKING:
         MapElementsStrategy,
         NullBehavior,
         NumericLiteral,
+        OverflowPolicy,
         PolarsDataType,
         RankMethod,
         RollingInterpolationMethod,
@@ -2990,6 +2991,95 @@ class Expr:
         """
         return self._from_pyexpr(self._pyexpr.var(ddof))
 
+    def weighted_mean(self, weights: IntoExpr) -> Expr:
+        """
+        Get the weighted mean, using `weights` as the weight of every value.
+
+        Values or weights that are null are ignored.
+
+        Parameters
+        ----------
+        weights
+            Expression (or column name) with the weight of every value.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [1, 2, None, 4], "w": [3, 1, 2, None]})
+        >>> df.select(pl.col("a").weighted_mean("w"))
+        shape: (1, 1)
+        ┌──────┐
+        │ a    │
+        │ ---  │
+        │ f64  │
+        ╞══════╡
+        │ 1.25 │
+        └──────┘
+        """
+        weights = parse_into_expression(weights)
+        return self._from_pyexpr(self._pyexpr.weighted_mean(weights))
+
+    def weighted_sum(self, weights: IntoExpr) -> Expr:
+        """
+        Get the weighted sum, using `weights` as the weight of every value.
+
+        Values or weights that are null are ignored. If both the values and the
+        weights are integers, the result wraps around on overflow.
+
+        Parameters
+        ----------
+        weights
+            Expression (or column name) with the weight of every value.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [1, 2, None, 4], "w": [3, 1, 2, None]})
+        >>> df.select(pl.col("a").weighted_sum("w"))
+        shape: (1, 1)
+        ┌─────┐
+        │ a   │
+        │ --- │
+        │ i64 │
+        ╞═════╡
+        │ 5   │
+        └─────┘
+        """
+        weights = parse_into_expression(weights)
+        return self._from_pyexpr(self._pyexpr.weighted_sum(weights))
+
+    def weighted_quantile(self, weights: IntoExpr, quantile: float) -> Expr:
+        """
+        Get the weighted quantile, using `weights` as the weight of every value.
+
+        This is the smallest value for which the cumulative weight of all values up
+        to and including it reaches `quantile` times the total weight. Values or
+        weights that are null are ignored.
+
+        Parameters
+        ----------
+        weights
+            Expression (or column name) with the non-negative weight of every value.
+        quantile
+            Quantile between 0.0 and 1.0.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [3, 1, 10], "w": [1, 3, 2]})
+        >>> df.select(pl.col("a").weighted_quantile("w", 0.6))
+        shape: (1, 1)
+        ┌─────┐
+        │ a   │
+        │ --- │
+        │ i64 │
+        ╞═════╡
+        │ 3   │
+        └─────┘
+        """
+        if not 0.0 <= quantile <= 1.0:
+            msg = f"`quantile` must be between 0.0 and 1.0, got {quantile!r}"
+            raise ValueError(msg)
+        weights = parse_into_expression(weights)
+        return self._from_pyexpr(self._pyexpr.weighted_quantile(weights, quantile))
+
     def max(self) -> Expr:
         """
         Get maximum value.
@@ -3072,14 +3162,31 @@ class Expr:
         """
         return self._from_pyexpr(self._pyexpr.nan_min())
 
-    def sum(self) -> Expr:
+    def sum(self, *, overflow: OverflowPolicy = "wrap") -> Expr:
         """
         Get sum value.
 
+        Parameters
+        ----------
+        overflow : {'wrap', 'saturate', 'error', 'upcast'}
+            How to handle integer overflow.
+
+            - 'wrap': wrap around.
+            - 'saturate': clamp to the minimum or maximum value of the dtype.
+            - 'error': raise a :class:`ComputeError`.
+            - 'upcast': cast to Int64 or UInt64 first, and raise a
+              :class:`ComputeError` if the result still overflows.
+
+            Arithmetic on a signed integer and UInt64 with a policy other than
+            'wrap' raises an :class:`InvalidOperationError`, as their supertype
+            is not an integer.
+
         Notes
         -----
         Dtypes in {Int8, UInt8, Int16, UInt16} are cast to
         Int64 before summing to prevent overflow issues.
+        Other integer dtypes keep their dtype and wrap around on overflow,
+        unless a different `overflow` policy is given.
 
         Examples
         --------
@@ -3093,7 +3200,20 @@ class Expr:
         ╞═════╡
         │  0  │
         └─────┘
+
+        >>> df = pl.DataFrame({"a": [2**31 - 1, 1]}, schema={"a": pl.Int32})
+        >>> df.select(pl.col("a").sum(overflow="saturate"))
+        shape: (1, 1)
+        ┌────────────┐
+        │ a          │
+        │ ---        │
+        │ i32        │
+        ╞════════════╡
+        │ 2147483647 │
+        └────────────┘
         """
+        if overflow != "wrap":
+            return self._from_pyexpr(self._pyexpr.sum_with_overflow(overflow))
         return self._from_pyexpr(self._pyexpr.sum())
 
     def mean(self) -> Expr:
@@ -5324,7 +5444,7 @@ class Expr:
         other = parse_into_expression(other, str_as_lit=True)
         return self._from_pyexpr(self._pyexpr.neq_missing(other))
 
-    def add(self, other: Any) -> Expr:
+    def add(self, other: Any, *, overflow: OverflowPolicy = "wrap") -> Expr:
         """
         Method equivalent of addition operator `expr + other`.
 
@@ -5332,6 +5452,8 @@ class Expr:
         ----------
         other
             numeric or string value; accepts expression input.
+        overflow : {'wrap', 'saturate', 'error', 'upcast'}
+            How to handle integer overflow. See :meth:`Expr.sum`.
 
         Examples
         --------
@@ -5368,6 +5490,9 @@ class Expr:
         │ g   ┆ h   ┆ i   ┆ ghi │
         └─────┴─────┴─────┴─────┘
         """
+        if overflow != "wrap":
+            other = parse_into_expression(other, str_as_lit=True)
+            return self._from_pyexpr(self._pyexpr.add_with_overflow(other, overflow))
         return self.__add__(other)
 
     def floordiv(self, other: Any) -> Expr:
@@ -5485,7 +5610,7 @@ class Expr:
         """
         return self.__mod__(other)
 
-    def mul(self, other: Any) -> Expr:
+    def mul(self, other: Any, *, overflow: OverflowPolicy = "wrap") -> Expr:
         """
         Method equivalent of multiplication operator `expr * other`.
 
@@ -5493,6 +5618,8 @@ class Expr:
         ----------
         other
             Numeric literal or expression value.
+        overflow : {'wrap', 'saturate', 'error', 'upcast'}
+            How to handle integer overflow. See :meth:`Expr.sum`.
 
         Examples
         --------
@@ -5514,9 +5641,12 @@ class Expr:
         │ 16  ┆ 32  ┆ 64.0      │
         └─────┴─────┴───────────┘
         """
+        if overflow != "wrap":
+            other = parse_into_expression(other)
+            return self._from_pyexpr(self._pyexpr.mul_with_overflow(other, overflow))
         return self.__mul__(other)
 
-    def sub(self, other: Any) -> Expr:
+    def sub(self, other: Any, *, overflow: OverflowPolicy = "wrap") -> Expr:
         """
         Method equivalent of subtraction operator `expr - other`.
 
@@ -5524,6 +5654,8 @@ class Expr:
         ----------
         other
             Numeric literal or expression value.
+        overflow : {'wrap', 'saturate', 'error', 'upcast'}
+            How to handle integer overflow. See :meth:`Expr.sum`.
 
         Examples
         --------
@@ -5545,6 +5677,9 @@ class Expr:
         │ 4   ┆ 2   ┆ -6     │
         └─────┴─────┴────────┘
         """
+        if overflow != "wrap":
+            other = parse_into_expression(other)
+            return self._from_pyexpr(self._pyexpr.sub_with_overflow(other, overflow))
         return self.__sub__(other)
 
     def neg(self) -> Expr:
@@ -5757,6 +5892,22 @@ class Expr:
         │ [1, 2]    ┆ 2                ┆ true     │
         │ [9, 10]   ┆ 3                ┆ false    │
         └───────────┴──────────────────┴──────────┘
+
+        Check if combinations of values in multiple columns are present in another
+        frame by packing them into a struct:
+
+        >>> df = pl.DataFrame({"a": [1, 2, 3], "b": ["x", "y", "z"]})
+        >>> pairs = pl.DataFrame({"a": [1, 3], "b": ["x", "y"]})
+        >>> pairs = pairs.select(pl.struct("a", "b")).to_series()
+        >>> df.filter(pl.struct("a", "b").is_in(pairs))
+        shape: (1, 2)
+        ┌─────┬─────┐
+        │ a   ┆ b   │
+        │ --- ┆ --- │
+        │ i64 ┆ str │
+        ╞═════╪═════╡
+        │ 1   ┆ x   │
+        └─────┴─────┘
         """
         if isinstance(other, Collection) and not isinstance(other, str):
             if isinstance(other, (Set, FrozenSet)):
@@ -8368,6 +8519,7 @@ class Expr:
         *,
         descending: bool = False,
         seed: int | None = None,
+        pct: bool = False,
     ) -> Expr:
         """
         Assign ranks to data, dealing with ties appropriately.
@@ -8396,6 +8548,10 @@ class Expr:
             Rank in descending order.
         seed
             If `method="random"`, use this as seed.
+        pct
+            Scale the ranks to the range `(0, 1]` by dividing them by the number of
+            non-null values (or by the number of distinct values for `method="dense"`).
+            The result is always of data type :class:`Float64`.
 
         Examples
         --------
@@ -8449,8 +8605,24 @@ class Expr:
         │ 2   ┆ 14  ┆ 3.0  │
         │ 2   ┆ 11  ┆ 2.0  │
         └─────┴─────┴──────┘
+
+        Use `pct=True` to get percentile ranks within each group:
+
+        >>> df.with_columns(pl.col("b").rank(pct=True).over("a").alias("pct"))
+        shape: (5, 3)
+        ┌─────┬─────┬──────────┐
+        │ a   ┆ b   ┆ pct      │
+        │ --- ┆ --- ┆ ---      │
+        │ i64 ┆ i64 ┆ f64      │
+        ╞═════╪═════╪══════════╡
+        │ 1   ┆ 6   ┆ 0.5      │
+        │ 1   ┆ 7   ┆ 1.0      │
+        │ 2   ┆ 5   ┆ 0.333333 │
+        │ 2   ┆ 14  ┆ 1.0      │
+        │ 2   ┆ 11  ┆ 0.666667 │
+        └─────┴─────┴──────────┘
         """
-        return self._from_pyexpr(self._pyexpr.rank(method, descending, seed))
+        return self._from_pyexpr(self._pyexpr.rank(method, descending, seed, pct))
 
     def diff(self, n: int = 1, null_behavior: NullBehavior = "ignore") -> Expr:
         """
@@ -9990,6 +10162,9 @@ class Expr:
         Shrink to the dtype needed to fit the extrema of this [`Series`].
         This can be used to reduce memory pressure.
 
+        Integer columns keep their signedness. Floating point columns are always cast
+        to `Float32`, which may lose precision. Other dtypes are left unchanged.
+
         Examples
         --------
         >>> pl.DataFrame(
@@ -10014,6 +10189,12 @@ class Expr:
         │ 2   ┆ 2          ┆ 2          ┆ 2    ┆ 2    ┆ b   ┆ 1.32 ┆ null  │
         │ 3   ┆ 8589934592 ┆ 1073741824 ┆ 112  ┆ 129  ┆ c   ┆ 0.12 ┆ false │
         └─────┴────────────┴────────────┴──────┴──────┴─────┴──────┴───────┘
+
+        To shrink all numeric columns of a DataFrame, apply it to every column.
+
+        >>> df = pl.DataFrame({"a": [1, 2, 3], "b": [0, 300, 70000]})
+        >>> df.select(pl.all().shrink_dtype()).schema
+        Schema({'a': Int8, 'b': Int32})
         """
         return self._from_pyexpr(self._pyexpr.shrink_dtype())
 
@@ -153,9 +153,11 @@ class PolarsDataFrame(InterchangeDataFrame):
         names
             Column names.
         """
-        if not isinstance(names, Sequence):
+        if not isinstance(names, Sequence) or isinstance(names, str):
             msg = "`names` is not a sequence"
             raise TypeError(msg)
+        if not isinstance(names, list):
+            names = list(names)
 
         return PolarsDataFrame(
             self._df.select(names),
@@ -367,6 +367,7 @@ def scan_ipc(
     hive_schema: SchemaDict | None = None,
     try_parse_hive_dates: bool = True,
     include_file_paths: str | None = None,
+    unify_schemas: bool = False,
 ) -> LazyFrame:
     """
     Lazily read from an Arrow IPC (Feather v2) file or multiple files via glob patterns.
@@ -431,7 +432,15 @@ def scan_ipc(
         Whether to try parsing hive values as date/datetime types.
     include_file_paths
         Include the path of the source file(s) as a column with this name.
+    unify_schemas
+        Allow the files to have differing schemas. Columns that are missing from a
+        file are filled with nulls and differing data types are cast to their
+        supertype. Cannot be combined with `hive_partitioning=True`.
     """
+    if unify_schemas and hive_partitioning:
+        msg = "`unify_schemas` cannot be combined with `hive_partitioning=True`"
+        raise ValueError(msg)
+
     sources: list[str] | list[Path] | list[IO[bytes]] | list[bytes] = []
     if isinstance(source, (str, Path)):
         source = normalize_filepath(source, check_not_directory=False)
@@ -463,5 +472,6 @@ def scan_ipc(
         hive_schema=hive_schema,
         try_parse_hive_dates=try_parse_hive_dates,
         include_file_paths=include_file_paths,
+        unify_schemas=unify_schemas,
     )
     return wrap_ldf(pylf)
@@ -57,6 +57,7 @@ def read_parquet(
     low_memory: bool = False,
     storage_options: dict[str, Any] | None = None,
     retries: int = 2,
+    unify_schemas: bool = False,
     use_pyarrow: bool = False,
     pyarrow_options: dict[str, Any] | None = None,
     memory_map: bool = True,
@@ -130,6 +131,10 @@ def read_parquet(
         from environment variables.
     retries
         Number of retries if accessing a cloud instance fails.
+    unify_schemas
+        Allow the files to have differing schemas. Columns that are missing from a
+        file are filled with nulls and differing data types are cast to their
+        supertype. Only valid when `use_pyarrow=False`.
     use_pyarrow
         Use PyArrow instead of the Rust-native Parquet reader. The PyArrow reader is
         more stable.
@@ -198,6 +203,7 @@ def read_parquet(
         retries=retries,
         glob=glob,
         include_file_paths=None,
+        unify_schemas=unify_schemas,
     )
 
     if columns is not None:
@@ -307,6 +313,7 @@ def scan_parquet(
     storage_options: dict[str, Any] | None = None,
     retries: int = 2,
     include_file_paths: str | None = None,
+    unify_schemas: bool = False,
 ) -> LazyFrame:
     """
     Lazily read from a local or cloud-hosted parquet file (or files).
@@ -388,6 +395,10 @@ def scan_parquet(
         Number of retries if accessing a cloud instance fails.
     include_file_paths
         Include the path of the source file(s) as a column with this name.
+    unify_schemas
+        Allow the files to have differing schemas. Columns that are missing from a
+        file are filled with nulls and differing data types are cast to their
+        supertype. Cannot be combined with `hive_partitioning=True`.
 
     See Also
     --------
@@ -414,6 +425,9 @@ def scan_parquet(
     if hive_schema is not None:
         msg = "The `hive_schema` parameter of `scan_parquet` is considered unstable."
         issue_unstable_warning(msg)
+    if unify_schemas and hive_partitioning:
+        msg = "`unify_schemas` cannot be combined with `hive_partitioning=True`"
+        raise ValueError(msg)
 
     if isinstance(source, (str, Path)):
         source = normalize_filepath(source, check_not_directory=False)
@@ -439,6 +453,7 @@ def scan_parquet(
         retries=retries,
         glob=glob,
         include_file_paths=include_file_paths,
+        unify_schemas=unify_schemas,
     )
 
 
@@ -460,6 +475,7 @@ def _scan_parquet_impl(
     try_parse_hive_dates: bool = True,
     retries: int = 2,
     include_file_paths: str | None = None,
+    unify_schemas: bool = False,
 ) -> LazyFrame:
     if isinstance(source, list):
         sources = source
@@ -490,5 +506,6 @@ def _scan_parquet_impl(
         retries=retries,
         glob=glob,
         include_file_paths=include_file_paths,
+        unify_schemas=unify_schemas,
     )
     return wrap_ldf(pylf)
@@ -108,6 +108,7 @@ if TYPE_CHECKING:
         FrameInitTypes,
         IntoExpr,
         IntoExprColumn,
+        JoinAlgorithm,
         JoinStrategy,
         JoinValidation,
         Label,
@@ -2247,6 +2248,8 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         statistics: bool | str | dict[str, bool] = True,
         row_group_size: int | None = None,
         data_page_size: int | None = None,
+        dictionary_encoding: bool = True,
+        column_compression: dict[str, str] | None = None,
         maintain_order: bool = True,
         type_coercion: bool = True,
         predicate_pushdown: bool = True,
@@ -2304,6 +2307,14 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         data_page_size
             Size limit of individual data pages.
             If not set defaults to 1024 * 1024 bytes
+        dictionary_encoding
+            Allow dictionary encoding of columns. If `False`, all columns except
+            categoricals are written with plain encoding.
+        column_compression
+            Compression of individual columns, as a mapping from column name to one of
+            the `compression` options, which then use their default level. Only
+            top-level columns can be given; all fields of a nested column share its
+            compression. Columns that are not given use `compression`.
         maintain_order
             Maintain the order in which data is processed.
             Setting this to `False` will be slightly faster.
@@ -2362,6 +2373,10 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             statistics=statistics,
             row_group_size=row_group_size,
             data_page_size=data_page_size,
+            dictionary_encoding=dictionary_encoding,
+            column_compression=(
+                None if column_compression is None else list(column_compression.items())
+            ),
             maintain_order=maintain_order,
         )
 
@@ -4352,6 +4367,8 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         suffix: str = "_right",
         validate: JoinValidation = "m:m",
         join_nulls: bool = False,
+        coerce_keys: bool = True,
+        algorithm: JoinAlgorithm = "auto",
         coalesce: bool | None = None,
         allow_parallel: bool = True,
         force_parallel: bool = False,
@@ -4411,6 +4428,32 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
 
         join_nulls
             Join on null values. By default null values will never produce matches.
+        coerce_keys
+            Cast join keys of different but compatible data types to a common type
+            before joining, instead of raising an error. Integer and float keys are
+            cast to a supertype that holds all their values (e.g. `Int32` and `Int64`
+            are joined as `Int64`), and categorical keys joined with string keys are
+            cast to `String`. The key columns in the output have the casted type.
+        algorithm : {'auto', 'hash', 'sort_merge', 'broadcast'}
+            Algorithm used to join on a single key in an inner, left or right join.
+            Other joins always use a hash join.
+
+            * *auto*
+                Use a sort-merge join if the keys are sorted, a broadcast join if one
+                side is tiny, and a hash join otherwise.
+            * *hash*
+                Build a hash table from the shorter side.
+            * *sort_merge*
+                Merge the sorted keys, sorting them first where needed. The keys must
+                be numeric and may not contain nulls.
+            * *broadcast*
+                Compare the keys with every value of the build side instead of
+                building a hash table. The build side is the shorter side in an inner
+                join, and the right (left) side in a left (right) join.
+
+            .. note::
+                This is only used by the in-memory engine, and join validation
+                requires the 'auto' or 'hash' algorithm.
         coalesce
             Coalescing behavior (merging of join columns).
 
@@ -4532,6 +4575,8 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
                     how,
                     suffix,
                     validate,
+                    coerce_keys,
+                    algorithm,
                 )
             )
 
@@ -4557,6 +4602,8 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
                 how,
                 suffix,
                 validate,
+                coerce_keys,
+                algorithm,
                 coalesce,
             )
         )
@@ -39,5 +39,9 @@ class InProcessQuery:
             return None
 
     def fetch_blocking(self) -> DataFrame:
-        """Await the result synchronously."""
+        """
+        Await the result synchronously.
+
+        Interrupting while waiting (e.g. with `Ctrl-C`) cancels the query.
+        """
         return wrap_df(self._inner.fetch_blocking())
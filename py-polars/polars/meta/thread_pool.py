@@ -21,6 +21,10 @@ def thread_pool_size() -> int:
     PySpark UDF or similar context. Otherwise, it is strongly recommended not to
     override this value as it will be set automatically by the engine.
 
+    See Also
+    --------
+    set_num_threads
+
     Examples
     --------
     >>> pl.thread_pool_size()  # doctest: +SKIP
@@ -29,6 +33,38 @@ def thread_pool_size() -> int:
     return plr.thread_pool_size()
 
 
+def set_num_threads(n: int) -> None:
+    """
+    Set the number of threads in the Polars thread pool.
+
+    This takes precedence over the `POLARS_MAX_THREADS` environment variable.
+
+    Parameters
+    ----------
+    n
+        Number of threads, must be positive.
+
+    Raises
+    ------
+    InvalidOperationError
+        If `n` is zero, or if the number of threads was already set, either by an
+        earlier call or because the thread pool is running.
+
+    Notes
+    -----
+    The thread pool is created the first time Polars runs anything in parallel,
+    and its size cannot change afterwards. This function must therefore be called
+    right after importing Polars, and can only be called once.
+
+    Examples
+    --------
+    >>> pl.set_num_threads(4)  # doctest: +SKIP
+    >>> pl.thread_pool_size()  # doctest: +SKIP
+    4
+    """
+    plr.set_num_threads(n)
+
+
 @deprecate_renamed_function("thread_pool_size", version="0.20.7")
 def threadpool_size() -> int:
     """
@@ -134,6 +134,7 @@ if TYPE_CHECKING:
         NonNestedLiteral,
         NullBehavior,
         NumericLiteral,
+        OverflowPolicy,
         PolarsDataType,
         PythonLiteral,
         RankMethod,
@@ -1909,32 +1910,55 @@ class Series:
         stats.columns = ["statistic", "value"]
         return stats.filter(F.col("value").is_not_null())
 
-    def sum(self) -> int | float:
+    def sum(self, *, overflow: OverflowPolicy = "wrap") -> int | float:
         """
         Reduce this Series to the sum value.
 
+        Parameters
+        ----------
+        overflow : {'wrap', 'saturate', 'error', 'upcast'}
+            How to handle integer overflow. See :meth:`Expr.sum`.
+
         Notes
         -----
         Dtypes in {Int8, UInt8, Int16, UInt16} are cast to
         Int64 before summing to prevent overflow issues.
+        Other integer dtypes keep their dtype and wrap around on overflow,
+        unless a different `overflow` policy is given.
+        Boolean values are summed as the number of `True` values.
 
         Examples
         --------
         >>> s = pl.Series("a", [1, 2, 3])
         >>> s.sum()
         6
+        >>> pl.Series([True, None, False, True]).sum()
+        2
+        >>> pl.Series([2**31 - 1, 1], dtype=pl.Int32).sum(overflow="upcast")
+        2147483648
         """
+        if overflow != "wrap":
+            return (
+                self.to_frame()
+                .select_seq(F.col(self.name).sum(overflow=overflow))
+                .item()
+            )
         return self._s.sum()
 
     def mean(self) -> PythonLiteral | None:
         """
         Reduce this Series to the mean value.
 
+        Null values are ignored. The mean of a Boolean Series is the fraction of
+        `True` values.
+
         Examples
         --------
         >>> s = pl.Series("a", [1, 2, 3])
         >>> s.mean()
         2.0
+        >>> pl.Series([True, None, False, True]).mean()
+        0.6666666666666666
         """
         return self._s.mean()
 
@@ -2094,6 +2118,96 @@ class Series:
         """
         return self._s.var(ddof)
 
+    def weighted_mean(self, weights: Series | ArrayLike) -> float | None:
+        """
+        Get the weighted mean of this Series.
+
+        Every value is weighted by the matching element of `weights`; values or
+        weights that are null are ignored.
+
+        Parameters
+        ----------
+        weights
+            Series (or array) with the weight of every value.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [1, 2, None, 4])
+        >>> s.weighted_mean([3, 1, 2, None])
+        1.25
+        """
+        weights = self._weights_series(weights)
+        return (
+            self.to_frame()
+            .select_seq(F.col(self.name).weighted_mean(F.lit(weights)))
+            .item()
+        )
+
+    def weighted_sum(self, weights: Series | ArrayLike) -> int | float | None:
+        """
+        Get the weighted sum of this Series.
+
+        Every value is weighted by the matching element of `weights`; values or
+        weights that are null are ignored. If both the values and the weights are
+        integers, the result wraps around on overflow.
+
+        Parameters
+        ----------
+        weights
+            Series (or array) with the weight of every value.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [1, 2, None, 4])
+        >>> s.weighted_sum([3, 1, 2, None])
+        5
+        """
+        weights = self._weights_series(weights)
+        return (
+            self.to_frame()
+            .select_seq(F.col(self.name).weighted_sum(F.lit(weights)))
+            .item()
+        )
+
+    def weighted_quantile(
+        self, weights: Series | ArrayLike, quantile: float
+    ) -> PythonLiteral | None:
+        """
+        Get the weighted quantile of this Series.
+
+        This is the smallest value for which the cumulative weight of all values up
+        to and including it reaches `quantile` times the total weight. Values or
+        weights that are null are ignored.
+
+        Parameters
+        ----------
+        weights
+            Series (or array) with the non-negative weight of every value.
+        quantile
+            Quantile between 0.0 and 1.0.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [3, 1, 10])
+        >>> s.weighted_quantile([1, 3, 2], 0.6)
+        3
+        """
+        weights = self._weights_series(weights)
+        return (
+            self.to_frame()
+            .select_seq(F.col(self.name).weighted_quantile(F.lit(weights), quantile))
+            .item()
+        )
+
+    def _weights_series(self, weights: Series | ArrayLike) -> Series:
+        if not isinstance(weights, Series):
+            weights = Series(weights)
+        if len(self) != len(weights):
+            n, m = len(self), len(weights)
+            msg = f"Series length mismatch: expected {n!r}, found {m!r}"
+            raise ShapeError(msg)
+        return weights
+
     def median(self) -> PythonLiteral | None:
         """
         Get the median of this Series.
@@ -5250,6 +5364,45 @@ class Series:
         ]
         """
 
+    @unstable()
+    def map_chunks(
+        self, function: Callable[[ArrowArrayExportable], ArrowArrayExportable]
+    ) -> Series:
+        """
+        Apply a custom function to every chunk of this Series.
+
+        Every chunk is passed to `function` as an Arrow array that can be imported
+        through the Arrow PyCapsule Interface (`__arrow_c_array__`), for example with
+        `pyarrow.array` or in a compiled extension. The arrays that `function` returns
+        are imported the same way and form the chunks of the result, so no data is
+        copied to or from Python objects.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        function
+            Function that takes an Arrow array and returns an object implementing
+            `__arrow_c_array__`. All returned arrays must have the same data type.
+
+        Examples
+        --------
+        >>> import pyarrow as pa
+        >>> import pyarrow.compute as pc
+        >>> s = pl.Series("a", [1, 2, 3])
+        >>> s.map_chunks(lambda chunk: pc.multiply(pa.array(chunk), 2))
+        shape: (3,)
+        Series: 'a' [i64]
+        [
+                2
+                4
+                6
+        ]
+        """
+        return self._from_pyseries(self._s.map_chunks(function))
+
     def map_elements(
         self,
         function: Callable[[Any], Any],
@@ -6216,6 +6369,7 @@ class Series:
         *,
         descending: bool = False,
         seed: int | None = None,
+        pct: bool = False,
     ) -> Series:
         """
         Assign ranks to data, dealing with ties appropriately.
@@ -6244,6 +6398,10 @@ class Series:
             Rank in descending order.
         seed
             If `method="random"`, use this as seed.
+        pct
+            Scale the ranks to the range `(0, 1]` by dividing them by the number of
+            non-null values (or by the number of distinct values for `method="dense"`).
+            The result is always of data type :class:`Float64`.
 
         Examples
         --------
@@ -7281,6 +7439,9 @@ class Series:
         Shrink to the dtype needed to fit the extrema of this [`Series`].
         This can be used to reduce memory pressure.
 
+        Integer columns keep their signedness. Floating point columns are always cast
+        to `Float32`, which may lose precision. Other dtypes are left unchanged.
+
         Examples
         --------
         >>> s = pl.Series("a", [1, 2, 3, 4, 5, 6])
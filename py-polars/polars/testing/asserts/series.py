@@ -311,7 +311,9 @@ def _assert_series_values_within_tolerance(
 
     difference = (left_unequal - right_unequal).abs()
     tolerance = atol + rtol * right_unequal.abs()
-    exceeds_tolerance = difference > tolerance
+    # unequal values are never within tolerance if either one is infinite
+    infinite = left_unequal.is_infinite() | right_unequal.is_infinite()
+    exceeds_tolerance = (difference > tolerance) | infinite
 
     if exceeds_tolerance.any():
         raise_assertion_error(
@@ -241,6 +241,8 @@ fn polars(py: Python, m: &Bound<PyModule>) -> PyResult<()> {
         .unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::thread_pool_size))
         .unwrap();
+    m.add_wrapped(wrap_pyfunction!(functions::set_num_threads))
+        .unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::enable_string_cache))
         .unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::disable_string_cache))
@@ -391,3 +391,24 @@ def test_fallback_with_dtype_strict_failure_decimal_precision() -> None:
         TypeError, match="decimal precision 3 can't fit values with 5 digits"
     ):
         PySeries.new_from_any_values_and_dtype("", values, dtype, strict=True)
+
+
+@pytest.mark.parametrize("strict", [True, False])
+def test_fallback_without_dtype_none_to_null(strict: bool) -> None:
+    result = wrap_s(PySeries.new_from_any_values("", [None, None], strict=strict))
+    assert result.dtype == pl.Null
+    assert result.to_list() == [None, None]
+
+    result = wrap_s(PySeries.new_from_any_values("", [None, 1, None], strict=strict))
+    assert result.dtype == pl.Int64
+    assert result.to_list() == [None, 1, None]
+
+
+def test_fallback_without_dtype_mixed_types_strict_vs_nonstrict() -> None:
+    values = [1, "a", None]
+    with pytest.raises(TypeError, match="Try setting `strict=False`"):
+        PySeries.new_from_any_values("", values, strict=True)
+
+    result = wrap_s(PySeries.new_from_any_values("", values, strict=False))
+    assert result.dtype == pl.String
+    assert result.to_list() == ["1", "a", None]
@@ -1504,6 +1504,20 @@ def test_datetime_date_subclasses() -> None:
     assert_series_equal(result, expected)
 
 
+def test_time_timedelta_subclasses() -> None:
+    class FakeTime(time): ...
+
+    class FakeTimedelta(timedelta): ...
+
+    result = pl.Series([FakeTime(12, 30)])
+    expected = pl.Series([time(12, 30)])
+    assert_series_equal(result, expected)
+
+    result = pl.Series([FakeTimedelta(days=1, seconds=5)])
+    expected = pl.Series([timedelta(days=1, seconds=5)])
+    assert_series_equal(result, expected)
+
+
 def test_list_null_constructor() -> None:
     s = pl.Series("a", [[None], [None]], dtype=pl.List(pl.Null))
     assert s.dtype == pl.List(pl.Null)
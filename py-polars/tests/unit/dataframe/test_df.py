@@ -633,6 +633,18 @@ def test_hstack_dataframe(in_place: bool) -> None:
         assert_frame_equal(df_out, expected)
 
 
+@pytest.mark.parametrize("in_place", [True, False])
+def test_hstack_series(in_place: bool) -> None:
+    df = pl.DataFrame({"a": [2, 1, 3]})
+    s = pl.Series("b", ["a", "b", "c"])
+    expected = pl.DataFrame({"a": [2, 1, 3], "b": ["a", "b", "c"]})
+    if in_place:
+        df.hstack(s, in_place=True)
+        assert_frame_equal(df, expected)
+    else:
+        assert_frame_equal(df.hstack(s), expected)
+
+
 def test_file_buffer() -> None:
     f = BytesIO()
     f.write(b"1,2,3,4,5,6\n7,8,9,10,11,12")
@@ -86,3 +86,10 @@ def test_glimpse_colname_length() -> None:
 
     expected = f"$ {'a' * 95}… <i64> 1, 2, 3"
     assert result.strip().split("\n")[-1] == expected
+
+
+def test_glimpse_empty() -> None:
+    assert pl.DataFrame().glimpse(return_as_string=True) == "Rows: 0\nColumns: 0\n"
+
+    result = pl.DataFrame(schema={"a": pl.Int64}).glimpse(return_as_string=True)
+    assert result == "Rows: 0\nColumns: 1\n$ a <i64> \n"
@@ -77,3 +77,17 @@ def test_series_repr_html_max_rows_default() -> None:
 
     expected_rows = 10
     assert html.count("<td>") - 2 == expected_rows
+
+
+def test_df_repr_html_max_cols_odd() -> None:
+    df = pl.DataFrame({f"c{i}": [i] for i in range(10)})
+
+    with pl.Config(tbl_cols=5):
+        html = df._repr_html_()
+
+    # the first three and last two columns, plus the ellipsis column
+    expected_cols = 5
+    assert html.count("<th>") - 1 == expected_cols
+    assert "<th>c2</th>" in html
+    assert "<th>c8</th>" in html
+    assert "<th>c3</th>" not in html
@@ -137,12 +137,24 @@ def test_select_columns_by_name() -> None:
     assert_frame_equal(out._df, expected)
 
 
+def test_select_columns_by_name_nonlist_input() -> None:
+    df = pl.DataFrame({"a": [1, 2], "b": [3, 4], "c": [5, 6]})
+    dfi = PolarsDataFrame(df)
+
+    out = dfi.select_columns_by_name(("c",))
+
+    expected = pl.DataFrame({"c": [5, 6]})
+    assert_frame_equal(out._df, expected)
+
+
 def test_select_columns_by_name_invalid_input() -> None:
     df = pl.DataFrame({"a": [1, 2], "b": [3, 4], "c": [5, 6]})
     dfi = PolarsDataFrame(df)
 
     with pytest.raises(TypeError):
         dfi.select_columns_by_name(1)  # type: ignore[arg-type]
+    with pytest.raises(TypeError):
+        dfi.select_columns_by_name("a")
 
 
 @pytest.mark.parametrize("n_chunks", [None, 2])
@@ -299,6 +299,27 @@ def test_csv_int_types() -> None:
     )
 
 
+@pytest.mark.parametrize("null_value", ["", "NA", "null"])
+def test_csv_roundtrip_nulls_and_special_floats(null_value: str) -> None:
+    df = pl.DataFrame(
+        {
+            "f64": [1.5, None, float("nan"), float("inf"), float("-inf")],
+            "f32": [-0.5, float("nan"), None, float("-inf"), 2.0],
+            "i64": [1, None, 3, 4, None],
+        },
+        schema_overrides={"f32": pl.Float32},
+    )
+    csv = df.write_csv(null_value=null_value)
+    out = pl.read_csv(
+        io.StringIO(csv), null_values=null_value, schema_overrides=df.schema
+    )
+    assert_frame_equal(out, df)
+
+    # The float dtype is also inferred from the written special values.
+    out = pl.read_csv(io.StringIO(csv), null_values=null_value)
+    assert_frame_equal(out, df.with_columns(pl.col("f32").cast(pl.Float64)))
+
+
 def test_csv_float_parsing() -> None:
     lines_with_floats = [
         "123.86,+123.86,-123.86\n",
@@ -306,6 +327,7 @@ def test_csv_float_parsing() -> None:
         "5.,+5.,-5.\n",
         "inf,+inf,-inf\n",
         "NaN,+NaN,-NaN\n",
+        "nan,Infinity,-INF\n",
     ]
 
     for line_with_floats in lines_with_floats:
@@ -221,6 +221,23 @@ def test_glob_ipc(df: pl.DataFrame, tmp_path: Path) -> None:
         assert_frame_equal(result, df, categorical_as_str=True)
 
 
+@pytest.mark.write_disk
+def test_scan_ipc_unify_schemas(tmp_path: Path) -> None:
+    df1 = pl.DataFrame({"a": [1, 2], "b": ["x", "y"]}, schema_overrides={"a": pl.Int32})
+    df2 = pl.DataFrame({"a": [3], "c": [True]})
+    df1.write_ipc(tmp_path / "1.ipc")
+    df2.write_ipc(tmp_path / "2.ipc")
+
+    with pytest.raises(pl.exceptions.SchemaError):
+        pl.scan_ipc(tmp_path / "*.ipc").collect()
+
+    result = pl.scan_ipc(tmp_path / "*.ipc", unify_schemas=True).collect()
+    expected = pl.DataFrame(
+        {"a": [1, 2, 3], "b": ["x", "y", None], "c": [None, None, True]}
+    )
+    assert_frame_equal(result, expected)
+
+
 def test_from_float16() -> None:
     # Create a feather file with a 16-bit floating point column
     pandas_df = pd.DataFrame({"column": [1.0]}, dtype="float16")
@@ -390,6 +390,13 @@ def test_empty_json() -> None:
     assert df.dtypes == [pl.Struct([])]
     assert df.shape == (0, 1)
 
+    df = pl.read_json(io.StringIO("[]"))
+    assert df.shape == (0, 0)
+
+    df = pl.read_json(b"[]", schema_overrides={"a": pl.Int64})
+    assert df.schema == {"a": pl.Int64}
+    assert df.height == 0
+
 
 def test_compressed_json() -> None:
     # shared setup
@@ -453,6 +453,28 @@ def test_parquet_schema_mismatch_panic_17067(tmp_path: Path, streaming: bool) ->
         pl.scan_parquet(tmp_path).collect(streaming=streaming)
 
 
+@pytest.mark.write_disk
+def test_parquet_unify_schemas(tmp_path: Path) -> None:
+    df1 = pl.DataFrame({"a": [1, 2], "b": ["x", "y"]}, schema_overrides={"a": pl.Int32})
+    df2 = pl.DataFrame({"a": [3], "c": [True]})
+    df1.write_parquet(tmp_path / "1.parquet")
+    df2.write_parquet(tmp_path / "2.parquet")
+
+    expected = pl.DataFrame(
+        {"a": [1, 2, 3], "b": ["x", "y", None], "c": [None, None, True]}
+    )
+    lf = pl.scan_parquet(tmp_path, unify_schemas=True)
+    assert_frame_equal(lf.collect(), expected)
+    assert_frame_equal(pl.read_parquet(tmp_path, unify_schemas=True), expected)
+    assert_frame_equal(
+        pl.scan_parquet(tmp_path, unify_schemas=True, n_rows=2).collect(),
+        expected.head(2),
+    )
+
+    with pytest.raises(ValueError, match="hive_partitioning"):
+        pl.scan_parquet(tmp_path, unify_schemas=True, hive_partitioning=True)
+
+
 @pytest.mark.write_disk
 def test_predicate_push_down_categorical_17744(tmp_path: Path) -> None:
     path = tmp_path / "1"
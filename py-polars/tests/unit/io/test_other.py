@@ -84,6 +84,23 @@ def test_copy() -> None:
     assert_series_equal(copy.deepcopy(a), a)
 
 
+def test_clone_mutation_does_not_affect_original() -> None:
+    s = pl.Series("a", [1, 2, 3])
+    s2 = s.clone()
+    s2[0] = 10
+    s2.append(pl.Series("a", [4]))
+    assert s.to_list() == [1, 2, 3]
+    assert s2.to_list() == [10, 2, 3, 4]
+
+    df = pl.DataFrame({"a": [1, 2], "b": ["x", "y"]})
+    df2 = df.clone()
+    df2[0, "a"] = 5
+    df2.insert_column(0, pl.Series("c", [True, False]))
+    assert df.columns == ["a", "b"]
+    assert df["a"].to_list() == [1, 2]
+    assert df2.columns == ["c", "a", "b"]
+
+
 def test_categorical_round_trip() -> None:
     df = pl.DataFrame({"ints": [1, 2, 3], "cat": ["a", "b", "c"]})
     df = df.with_columns(pl.col("cat").cast(pl.Categorical))
@@ -16,7 +16,7 @@ from hypothesis import given
 from hypothesis import strategies as st
 
 import polars as pl
-from polars.exceptions import ComputeError
+from polars.exceptions import ColumnNotFoundError, ComputeError, InvalidOperationError
 from polars.testing import assert_frame_equal, assert_series_equal
 from polars.testing.parametric import column, dataframes
 
@@ -333,6 +333,22 @@ def test_row_group_size_saturation() -> None:
     assert_frame_equal(pl.read_parquet(f), df)
 
 
+def test_row_group_size_upper_bound() -> None:
+    df = pl.DataFrame({"a": range(5)})
+    f = io.BytesIO()
+    df.write_parquet(f, row_group_size=2)
+
+    f.seek(0)
+    metadata = pq.read_metadata(f)
+    n_rows = [metadata.row_group(i).num_rows for i in range(metadata.num_row_groups)]
+    assert n_rows == [2, 2, 1]
+    f.seek(0)
+    assert_frame_equal(pl.read_parquet(f), df)
+
+    with pytest.raises(InvalidOperationError):
+        df.write_parquet(io.BytesIO(), row_group_size=0)
+
+
 def test_nested_sliced() -> None:
     for df in [
         pl.Series([[1, 2], [3, 4], [5, 6]]).slice(2, 2).to_frame(),
@@ -824,6 +840,53 @@ def test_parquet_string_rle_encoding() -> None:
     )
 
 
+def test_write_parquet_dictionary_encoding_and_column_compression() -> None:
+    df = pl.DataFrame({"a": [1, 1, 2, 2], "b": ["x", "x", "y", "y"]})
+
+    f = io.BytesIO()
+    df.write_parquet(
+        f,
+        compression="snappy",
+        dictionary_encoding=False,
+        column_compression={"b": "uncompressed"},
+    )
+    f.seek(0)
+    row_group = pq.read_metadata(f).row_group(0)
+    columns = [row_group.column(i) for i in range(row_group.num_columns)]
+    assert [c.compression for c in columns] == ["SNAPPY", "UNCOMPRESSED"]
+    assert all("RLE_DICTIONARY" not in c.encodings for c in columns)
+    f.seek(0)
+    assert_frame_equal(pl.read_parquet(f), df)
+
+    with pytest.raises(ColumnNotFoundError):
+        df.write_parquet(io.BytesIO(), column_compression={"c": "snappy"})
+    with pytest.raises(ValueError, match="column_compression"):
+        df.write_parquet(
+            io.BytesIO(), column_compression={"b": "snappy"}, use_pyarrow=True
+        )
+
+
+@pytest.mark.write_disk
+def test_sink_parquet_dictionary_encoding_and_column_compression(
+    tmp_path: Path,
+) -> None:
+    tmp_path.mkdir(exist_ok=True)
+    df = pl.DataFrame({"a": [1, 1, 2, 2], "b": ["x", "x", "y", "y"]})
+    file_path = tmp_path / "test.parquet"
+
+    df.lazy().sink_parquet(
+        file_path,
+        compression="snappy",
+        dictionary_encoding=False,
+        column_compression={"b": "uncompressed"},
+    )
+    row_group = pq.read_metadata(file_path).row_group(0)
+    columns = [row_group.column(i) for i in range(row_group.num_columns)]
+    assert [c.compression for c in columns] == ["SNAPPY", "UNCOMPRESSED"]
+    assert all("RLE_DICTIONARY" not in c.encodings for c in columns)
+    assert_frame_equal(pl.read_parquet(file_path), df)
+
+
 def test_sliced_dict_with_nulls_14904() -> None:
     df = (
         pl.DataFrame({"x": [None, None]})
@@ -1584,6 +1647,17 @@ def test_slice_roundtrip(df: pl.DataFrame, offset: int, length: int) -> None:
     assert_frame_equal(scanned, df.slice(offset, length))
 
 
+@pytest.mark.parametrize("parallel", ["row_groups", "columns", "none"])
+def test_slice_skipping_row_groups(parallel: pl.ParallelStrategy) -> None:
+    df = pl.DataFrame({"a": [1, 2, 3, 4, 5, 6], "b": ["u", "v", "w", "x", "y", "z"]})
+    f = io.BytesIO()
+    df.write_parquet(f, row_group_size=2)
+
+    f.seek(0)
+    result = pl.scan_parquet(f, parallel=parallel).slice(3, 10).collect()
+    assert_frame_equal(result, df.slice(3))
+
+
 def test_struct_prefiltered() -> None:
     df = pl.DataFrame({"a": {"x": 1, "y": 2}})
     f = io.BytesIO()
@@ -1,8 +1,12 @@
 from __future__ import annotations
 
+import subprocess
+import sys
+
 import pytest
 
 import polars as pl
+from polars.exceptions import InvalidOperationError
 
 
 def test_thread_pool_size() -> None:
@@ -14,3 +18,21 @@ def test_threadpool_size_deprecated() -> None:
     with pytest.deprecated_call():
         result = pl.threadpool_size()
     assert isinstance(result, int)
+
+
+def test_set_num_threads() -> None:
+    # The thread pool must not exist yet, so this runs in a new process.
+    code = (
+        "import polars as pl; pl.set_num_threads(3); "
+        "assert pl.thread_pool_size() == 3"
+    )
+    subprocess.run([sys.executable, "-c", code], check=True)
+
+
+def test_set_num_threads_running_pool() -> None:
+    n = pl.thread_pool_size()
+    with pytest.raises(InvalidOperationError, match="already set"):
+        pl.set_num_threads(n + 1)
+    with pytest.raises(InvalidOperationError, match="must be positive"):
+        pl.set_num_threads(0)
+    assert pl.thread_pool_size() == n
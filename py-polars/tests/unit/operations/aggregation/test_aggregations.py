@@ -7,7 +7,7 @@ import numpy as np
 import pytest
 
 import polars as pl
-from polars.exceptions import InvalidOperationError
+from polars.exceptions import ComputeError, InvalidOperationError, ShapeError
 from polars.testing import assert_frame_equal
 
 if TYPE_CHECKING:
@@ -748,3 +748,89 @@ def test_sort_by_over_multiple_nulls_last() -> None:
         }
     )
     assert_frame_equal(out, expected)
+
+
+def test_sum_overflow() -> None:
+    # small integers are summed as Int64
+    s = pl.Series([127, 1], dtype=pl.Int8)
+    assert s.sum() == 128
+    assert pl.select(s.sum()).to_series().dtype == pl.Int64
+
+    # wider integers keep their dtype and wrap around by default
+    s = pl.Series([2**31 - 1, 1], dtype=pl.Int32)
+    assert s.sum() == -(2**31)
+    assert s.sum(overflow="wrap") == -(2**31)
+    assert s.sum(overflow="saturate") == 2**31 - 1
+    assert s.sum(overflow="upcast") == 2**31
+    assert pl.select(s.sum(overflow="upcast")).to_series().dtype == pl.Int64
+    with pytest.raises(pl.exceptions.ComputeError, match="overflow"):
+        s.sum(overflow="error")
+    with pytest.raises(ValueError, match="`overflow` must be one of"):
+        s.sum(overflow="clamp")  # type: ignore[arg-type]
+
+
+def test_sum_overflow_group_by() -> None:
+    df = pl.DataFrame(
+        {"g": [1, 1, 2], "v": [2**31 - 1, 1, 5]}, schema_overrides={"v": pl.Int32}
+    )
+    out = (
+        df.group_by("g", maintain_order=True)
+        .agg(pl.col("v").sum(overflow="saturate"))
+        .sort("g")
+    )
+    expected = pl.DataFrame(
+        {"g": [1, 2], "v": [2**31 - 1, 5]}, schema_overrides={"v": pl.Int32}
+    )
+    assert_frame_equal(out, expected)
+
+
+def test_weighted_mean() -> None:
+    df = pl.DataFrame(
+        {
+            "g": ["a", "a", "a", "b", "b"],
+            "v": [1, 2, None, 4, 10],
+            "w": [1.0, 3.0, 5.0, None, 2.0],
+        }
+    )
+    assert df.select(pl.col("v").weighted_mean("w")).item() == 4.5
+    assert df["v"].weighted_mean(df["w"]) == 4.5
+
+    out = df.group_by("g").agg(pl.col("v").weighted_mean("w")).sort("g")
+    assert out["v"].to_list() == [1.75, 10.0]
+
+    with pytest.raises(ShapeError):
+        df["v"].weighted_mean([1.0, 2.0])
+
+
+def test_weighted_sum_and_quantile() -> None:
+    df = pl.DataFrame(
+        {
+            "g": ["a", "a", "a", "b", "b"],
+            "v": [3, 1, None, 4, 10],
+            "w": [1.0, 3.0, 5.0, None, 2.0],
+        }
+    )
+    assert df.select(pl.col("v").weighted_sum("w")).item() == 26.0
+    assert df["v"].weighted_sum(df["w"]) == 26.0
+
+    # The sorted values 1, 3, 10 have cumulative weights 3, 4, 6 out of 6.
+    quantiles = [df["v"].weighted_quantile(df["w"], q) for q in (0.0, 0.5, 0.6, 1.0)]
+    assert quantiles == [1, 1, 3, 10]
+
+    out = (
+        df.group_by("g")
+        .agg(
+            pl.col("v").weighted_sum("w").alias("sum"),
+            pl.col("v").weighted_quantile("w", 0.5).alias("q50"),
+        )
+        .sort("g")
+    )
+    assert out["sum"].to_list() == [6.0, 20.0]
+    assert out["q50"].to_list() == [1, 10]
+
+    with pytest.raises(ValueError, match="between 0.0 and 1.0"):
+        pl.col("v").weighted_quantile("w", 1.5)
+    with pytest.raises(ComputeError, match="non-negative weights"):
+        df.select(pl.col("v").weighted_quantile(pl.col("w") - 2.0, 0.5))
+    with pytest.raises(ShapeError):
+        df["v"].weighted_sum([1.0, 2.0])
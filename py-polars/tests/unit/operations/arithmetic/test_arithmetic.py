@@ -19,7 +19,11 @@ from polars import (
     UInt32,
     UInt64,
 )
-from polars.exceptions import ColumnNotFoundError, InvalidOperationError
+from polars.exceptions import (
+    ColumnNotFoundError,
+    ComputeError,
+    InvalidOperationError,
+)
 from polars.testing import assert_frame_equal, assert_series_equal
 from tests.unit.conftest import INTEGER_DTYPES, NUMERIC_DTYPES
 
@@ -587,6 +591,18 @@ def test_array_arithmetic_same_size(expected: Any, expr: pl.Expr) -> None:
     )
 
 
+def test_array_arithmetic_nulls_and_nested() -> None:
+    s = pl.Series("a", [[1, 2], None, [5, 6]], dtype=pl.Array(pl.Int64, 2))
+    assert_series_equal(
+        s + s,
+        pl.Series("a", [[2, 4], None, [10, 12]], dtype=pl.Array(pl.Int64, 2)),
+    )
+
+    nested = pl.Series("a", np.arange(8).reshape(2, 2, 2))
+    assert (nested + nested).dtype == pl.Array(pl.Array(pl.Int64, 2), 2)
+    assert (nested + nested).to_list() == [[[0, 2], [4, 6]], [[8, 10], [12, 14]]]
+
+
 def test_schema_owned_arithmetic_5669() -> None:
     df = (
         pl.LazyFrame({"A": [1, 2, 3]})
@@ -765,3 +781,37 @@ def test_date_datetime_sub() -> None:
 def test_raise_invalid_shape() -> None:
     with pytest.raises(pl.exceptions.InvalidOperationError):
         pl.DataFrame([[1, 2], [3, 4]]) * pl.DataFrame([1, 2, 3])
+
+
+def test_arithmetic_overflow() -> None:
+    df = pl.DataFrame(
+        {"a": [100, -100, None], "b": [100, 100, 1]},
+        schema={"a": pl.Int8, "b": pl.Int8},
+    )
+    out = df.select(
+        add=pl.col("a").add(pl.col("b"), overflow="saturate"),
+        sub=pl.col("a").sub(pl.col("b"), overflow="saturate"),
+        mul=pl.col("a").mul(2, overflow="upcast"),
+        wrap=pl.col("a").add(pl.col("b"), overflow="wrap"),
+    )
+    expected = pl.DataFrame(
+        {
+            "add": [127, 0, None],
+            "sub": [0, -128, None],
+            "mul": [200, -200, None],
+            "wrap": [-56, 0, None],
+        },
+        schema={"add": pl.Int8, "sub": pl.Int8, "mul": pl.Int64, "wrap": pl.Int8},
+    )
+    assert_frame_equal(out, expected)
+
+    with pytest.raises(ComputeError, match="integer overflow in `add`"):
+        df.select(pl.col("a").add(pl.col("b"), overflow="error"))
+
+    # floats are not affected by the policy
+    out = pl.select(pl.lit(1.5).mul(2, overflow="error"))
+    assert out.item() == 3.0
+
+    df = pl.DataFrame({"i": [1], "u": [1]}, schema={"i": pl.Int64, "u": pl.UInt64})
+    with pytest.raises(InvalidOperationError, match="requires an integer supertype"):
+        df.select(pl.col("i").add(pl.col("u"), overflow="saturate"))
@@ -81,6 +81,25 @@ def test_is_in_struct() -> None:
     }
 
 
+def test_is_in_struct_multiple_columns() -> None:
+    df = pl.DataFrame({"a": [1, 2, 3, 1], "b": ["x", "y", "z", "y"]})
+    pairs = pl.DataFrame({"a": [1, 3, 2], "b": ["x", "y", "y"]})
+    pairs_s = pairs.select(pl.struct("a", "b")).to_series()
+
+    keep = pl.struct("a", "b").is_in(pairs_s)
+    assert df.filter(keep).to_dict(as_series=False) == {
+        "a": [1, 2],
+        "b": ["x", "y"],
+    }
+    assert df.filter(~keep).to_dict(as_series=False) == {
+        "a": [3, 1],
+        "b": ["z", "y"],
+    }
+
+    # same result as a semi join
+    assert_frame_equal(df.filter(keep), df.join(pairs, on=["a", "b"], how="semi"))
+
+
 def test_is_in_null_prop() -> None:
     assert pl.Series([None], dtype=pl.Float32).is_in(pl.Series([42])).item() is None
     assert pl.Series([{"a": None}, None], dtype=pl.Struct({"a": pl.Float32})).is_in(
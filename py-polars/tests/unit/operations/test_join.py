@@ -18,7 +18,7 @@ from polars.exceptions import (
 from polars.testing import assert_frame_equal, assert_series_equal
 
 if TYPE_CHECKING:
-    from polars._typing import JoinStrategy
+    from polars._typing import JoinAlgorithm, JoinStrategy
 
 
 def test_semi_anti_join() -> None:
@@ -1036,3 +1036,56 @@ def test_join_coalesce_not_supported_warning() -> None:
     )
 
     assert_frame_equal(expect, got, check_row_order=False)
+
+
+def test_join_upcasts_numeric_keys() -> None:
+    left = pl.DataFrame({"a": pl.Series([1, 2, 3], dtype=pl.Int32), "b": [1, 2, 3]})
+    right = pl.DataFrame({"a": pl.Series([1, 3], dtype=pl.UInt16), "c": [4, 5]})
+
+    out = left.join(right, on="a")
+    assert out.schema == {"a": pl.Int32, "b": pl.Int64, "c": pl.Int64}
+    assert out.to_dict(as_series=False) == {"a": [1, 3], "b": [1, 3], "c": [4, 5]}
+
+    with pytest.raises(ComputeError, match="datatypes of join keys don't match"):
+        left.join(right.with_columns(pl.col("a").cast(pl.UInt64)), on="a")
+
+
+def test_join_coerce_keys() -> None:
+    left = pl.DataFrame({"a": pl.Series([1, 2, 3], dtype=pl.Int32), "b": [1, 2, 3]})
+    right = pl.DataFrame({"a": pl.Series([1, 3], dtype=pl.Int64), "c": [4, 5]})
+
+    with pytest.raises(ComputeError, match="datatypes of join keys don't match"):
+        left.join(right, on="a", coerce_keys=False)
+
+    left = left.with_columns(pl.col("a").cast(pl.String).cast(pl.Categorical))
+    right = right.with_columns(pl.col("a").cast(pl.String))
+    out = left.join(right, on="a", how="left")
+    assert out.schema == {"a": pl.String, "b": pl.Int64, "c": pl.Int64}
+    assert out.to_dict(as_series=False) == {
+        "a": ["1", "2", "3"],
+        "b": [1, 2, 3],
+        "c": [4, None, 5],
+    }
+
+
+@pytest.mark.parametrize("how", ["inner", "left", "right"])
+@pytest.mark.parametrize("algorithm", ["auto", "hash", "sort_merge", "broadcast"])
+def test_join_algorithm(how: JoinStrategy, algorithm: JoinAlgorithm) -> None:
+    left = pl.DataFrame({"a": [3, 1, 2, 5, 2], "l": [0, 1, 2, 3, 4]})
+    right = pl.DataFrame({"a": [2, 5, 7], "r": [0, 1, 2]})
+
+    expected = left.join(right, on="a", how=how, algorithm="hash")
+    out = left.join(right, on="a", how=how, algorithm=algorithm)
+    assert_frame_equal(out, expected, check_row_order=False)
+
+
+def test_join_algorithm_invalid() -> None:
+    left = pl.DataFrame({"a": [1, None], "l": [0, 1]})
+    right = pl.DataFrame({"a": [1], "r": [0]})
+
+    with pytest.raises(InvalidOperationError, match="sort-merge join requires"):
+        left.join(right, on="a", algorithm="sort_merge")
+    with pytest.raises(InvalidOperationError, match="join validation"):
+        left.join(right, on="a", validate="1:1", algorithm="broadcast")
+    with pytest.raises(ValueError, match="`algorithm` must be one of"):
+        left.join(right, on="a", algorithm="nested_loop")  # type: ignore[arg-type]
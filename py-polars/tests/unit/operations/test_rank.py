@@ -95,3 +95,23 @@ def test_rank_series() -> None:
 
     assert s.rank(method="average").dtype == pl.Float64
     assert s.rank(method="max").dtype == pl.get_index_type()
+
+
+def test_rank_pct() -> None:
+    s = pl.Series("a", [1, 2, None, 2, 4])
+
+    assert_series_equal(
+        s.rank(pct=True), pl.Series("a", [0.25, 0.625, None, 0.625, 1.0])
+    )
+    assert_series_equal(
+        s.rank("min", pct=True), pl.Series("a", [0.25, 0.5, None, 0.5, 1.0])
+    )
+    assert_series_equal(
+        s.rank("dense", pct=True),
+        pl.Series("a", [1 / 3, 2 / 3, None, 2 / 3, 1.0]),
+    )
+
+    df = pl.DataFrame({"g": [1, 1, 2, 2, 2], "v": [6, 7, 5, 14, 11]})
+    out = df.lazy().select(pl.col("v").rank("ordinal", pct=True).over("g"))
+    assert out.collect_schema()["v"] == pl.Float64
+    assert out.collect()["v"].to_list() == [0.5, 1.0, 1 / 3, 1.0, 2 / 3]
@@ -31,3 +31,11 @@ def test_rle_id() -> None:
 
     result_series = lf.collect().to_series().rle_id()
     assert_frame_equal(result_series.to_frame(), expected.collect())
+
+
+def test_rle_empty() -> None:
+    s = pl.Series("a", [], dtype=pl.Int32)
+    result = s.rle()
+    assert result.len() == 0
+    assert result.dtype == pl.Struct({"len": pl.get_index_type(), "value": pl.Int32})
+    assert s.rle_id().len() == 0
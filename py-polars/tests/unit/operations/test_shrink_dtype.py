@@ -44,3 +44,14 @@ def test_shrink_dtype() -> None:
         "j": [None, None, None],
         "k": [None, None, None],
     }
+
+
+def test_shrink_dtype_keeps_signedness() -> None:
+    assert pl.Series([0, 255], dtype=pl.UInt64).shrink_dtype().dtype == pl.UInt8
+    assert pl.Series([0, 256], dtype=pl.UInt64).shrink_dtype().dtype == pl.UInt16
+    assert pl.Series([0, 1 << 40], dtype=pl.UInt64).shrink_dtype().dtype == pl.UInt64
+    assert pl.Series([0, 127], dtype=pl.Int64).shrink_dtype().dtype == pl.Int8
+    assert pl.Series([-129, 0], dtype=pl.Int64).shrink_dtype().dtype == pl.Int16
+
+    s = pl.Series([0, 255], dtype=pl.UInt64)
+    assert s.shrink_dtype().to_list() == s.to_list()
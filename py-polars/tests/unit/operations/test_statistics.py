@@ -109,6 +109,16 @@ def test_cov_corr_f32_type() -> None:
     assert df.select(pl.corr("a", "b")).dtypes == [pl.Float32]
 
 
+@pytest.mark.parametrize("method", ["pearson", "spearman"])
+def test_corr_cov_length_mismatch(method: str) -> None:
+    a = pl.Series("a", [1.0, 2.0, 3.0])
+    b = pl.Series("b", [1.0, 2.0])
+    with pytest.raises(pl.exceptions.ShapeError):
+        pl.select(pl.corr(a, b, method=method))  # type: ignore[arg-type]
+    with pytest.raises(pl.exceptions.ShapeError):
+        pl.select(pl.cov(a, b))
+
+
 def test_cov(fruits_cars: pl.DataFrame) -> None:
     ldf = fruits_cars.lazy()
     cov_a_b = pl.cov(pl.col("A"), pl.col("B"))
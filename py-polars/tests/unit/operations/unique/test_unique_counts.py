@@ -41,3 +41,9 @@ def test_unique_counts_null() -> None:
     s = pl.Series([None, None, None])
     expected = pl.Series([3], dtype=pl.UInt32)
     assert_series_equal(s.unique_counts(), expected)
+
+
+def test_unique_counts_bool() -> None:
+    s = pl.Series("b", [True, None, True, False])
+    expected = pl.Series("b", [2, 1, 1], dtype=pl.UInt32)
+    assert_series_equal(s.unique_counts(), expected)
@@ -126,3 +126,29 @@ def test_series_describe_nested_list() -> None:
     }
     expected = pl.DataFrame({"statistic": stats.keys(), "value": stats.values()})
     assert_frame_equal(expected, result)
+
+
+def test_series_describe_percentiles() -> None:
+    s = pl.Series([1, 2, 3, 4, 5])
+
+    result = s.describe(percentiles=0.75)
+    assert result["statistic"].to_list() == [
+        "count",
+        "null_count",
+        "mean",
+        "std",
+        "min",
+        "75%",
+        "max",
+    ]
+    assert result.row(5) == ("75%", 4.0)
+
+    result = s.describe(percentiles=None)
+    assert result["statistic"].to_list() == [
+        "count",
+        "null_count",
+        "mean",
+        "std",
+        "min",
+        "max",
+    ]
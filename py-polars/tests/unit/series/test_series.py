@@ -7,6 +7,7 @@ from typing import TYPE_CHECKING, Any, Iterator, cast
 import numpy as np
 import pandas as pd
 import pyarrow as pa
+import pyarrow.compute as pc
 import pytest
 
 import polars
@@ -24,6 +25,7 @@ from polars.datatypes import (
     Unknown,
 )
 from polars.exceptions import (
+    ComputeError,
     InvalidOperationError,
     PolarsInefficientMapWarning,
     ShapeError,
@@ -636,6 +638,39 @@ def test_pycapsule_interface() -> None:
     assert out_arr == pa.array([1, 2, 3, None])
 
 
+def test_pycapsule_interface_chunks_round_trip() -> None:
+    s = pl.Series("a", [1, 2])
+    s.append(pl.Series("a", [3, None]))
+    assert s.n_chunks() == 2
+
+    # every chunk is exported as its own array and imported again as a chunk
+    out = pl.Series("a", PyCapsuleStreamHolder(s))
+    assert out.n_chunks() == 2
+    assert_series_equal(out, s)
+
+
+def test_map_chunks() -> None:
+    s = pl.Series("a", [1, 2])
+    s.append(pl.Series("a", [3, None]))
+
+    def double(chunk: Any) -> pa.Array:
+        return pc.multiply(pa.array(chunk), 2)
+
+    out = s.map_chunks(double)
+    assert out.n_chunks() == 2
+    assert_series_equal(out, pl.Series("a", [2, 4, 6, None]))
+
+    # the returned arrays may change the data type, but must agree on it
+    out = s.map_chunks(lambda chunk: pc.cast(pa.array(chunk), pa.float64()))
+    assert_series_equal(out, pl.Series("a", [1.0, 2.0, 3.0, None]))
+
+    dtypes = iter([pa.int64(), pa.float64()])
+    with pytest.raises(ComputeError, match="different types"):
+        s.map_chunks(lambda chunk: pc.cast(pa.array(chunk), next(dtypes)))
+    with pytest.raises(ValueError, match="__arrow_c_array__"):
+        s.map_chunks(lambda chunk: [1, 2])
+
+
 def test_get() -> None:
     a = pl.Series("a", [1, 2, 3])
     pos_idxs = pl.Series("idxs", [2, 0, 1, 0], dtype=pl.Int8)
@@ -1510,6 +1545,13 @@ def test_peak_max_peak_min() -> None:
     expected = pl.Series("a", [True, False, True, False, True])
     assert_series_equal(result, expected)
 
+    lf = pl.LazyFrame({"a": [4, 1, 3, 2, 5]})
+    q = lf.select(pl.col("a").peak_max().alias("max"), pl.col("a").peak_min())
+    assert q.collect_schema() == pl.Schema({"max": pl.Boolean, "a": pl.Boolean})
+
+    with pytest.raises(InvalidOperationError):
+        pl.Series(["a", "b"]).peak_max()
+
 
 def test_shrink_to_fit() -> None:
     s = pl.Series("a", [4, 1, 3, 2, 5])
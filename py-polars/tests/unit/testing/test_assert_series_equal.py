@@ -474,6 +474,18 @@ def test_assert_series_equal_passes_assertion(
             {"atol": 0.0, "rtol": 1e-2},
             id="approx_equal_float_right_nan_rtol",
         ),
+        pytest.param(
+            pl.Series([1.0, float("inf")]),
+            pl.Series([1.0, float("-inf")]),
+            {},
+            id="float_opposite_infinities",
+        ),
+        pytest.param(
+            pl.Series([1e308]),
+            pl.Series([float("inf")]),
+            {},
+            id="float_finite_vs_infinity",
+        ),
         pytest.param(
             pl.Series([[0.2, 0.3]]),
             pl.Series([[0.2, 0.3, 0.4]]),